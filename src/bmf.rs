@@ -0,0 +1,69 @@
+use crate::memory::{LeptonicaDestroy, RefCountedExclusive};
+
+use leptonica_sys::{bmfCreate, bmfDestroy, l_int32};
+use std::ffi::CStr;
+
+/// Wrapper around Leptonica's [`L_Bmf`](https://tpgit.github.io/Leptonica/struct_l___bmf.html) structure
+///
+/// A bitmap font for rendering text onto images.
+#[derive(Debug, PartialEq)]
+pub struct Bmf(*mut leptonica_sys::L_Bmf);
+
+impl AsRef<*mut leptonica_sys::L_Bmf> for Bmf {
+    fn as_ref(&self) -> &*mut leptonica_sys::L_Bmf {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::L_Bmf> for Bmf {
+    fn as_ref(&self) -> &leptonica_sys::L_Bmf {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::L_Bmf> for Bmf {
+    fn as_mut(&mut self) -> &mut leptonica_sys::L_Bmf {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Bmf {
+    /// Create a new Bmf from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid L_Bmf struct.
+    /// The L_Bmf struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::L_Bmf) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`bmfCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/bmf.c)
+    ///
+    /// Input: dir (directory holding the font pixa, or `None` to generate the built-in fonts
+    /// in memory), fontsize (4, 6, 8, 10, 12, 14, 16, 18 or 20)
+    pub fn create(dir: Option<&CStr>, fontsize: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let dir = match dir {
+            None => std::ptr::null(),
+            Some(dir) => dir.as_ptr(),
+        };
+        let ptr = unsafe { bmfCreate(dir, fontsize) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for Bmf {
+    unsafe fn destroy(&mut self) {
+        bmfDestroy(&mut self.0);
+    }
+}
+
+#[test]
+fn create_test() {
+    assert!(Bmf::create(None, 10).is_some());
+    assert!(Bmf::create(None, 11).is_none());
+}
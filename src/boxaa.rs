@@ -0,0 +1,169 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCounted, RefCountedExclusive},
+    Boxa, Numa,
+};
+
+use leptonica_sys::{
+    boxaDestroy, boxaaAddBoxa, boxaaCreate, boxaaDestroy, boxaaFlattenToBoxa, boxaaGetBoxa,
+    boxaaGetCount, l_int32, l_ok, numaDestroy, L_CLONE, L_COPY,
+};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`Boxaa`](https://tpgit.github.io/Leptonica/struct_boxaa.html) structure
+///
+/// An array of box arrays.
+#[derive(Debug, PartialEq)]
+pub struct Boxaa(*mut leptonica_sys::Boxaa);
+
+impl AsRef<leptonica_sys::Boxaa> for Boxaa {
+    fn as_ref(&self) -> &leptonica_sys::Boxaa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::Boxaa> for Boxaa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::Boxaa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Boxaa {
+    /// Create a new Boxaa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid Boxaa struct.
+    /// The Boxaa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::Boxaa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`boxaaCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/boxbasic.c)
+    ///
+    /// Input: n (initial number of ptrs)
+    pub fn create(n: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { boxaaCreate(n) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`boxaaAddBoxa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/boxbasic.c) with copied `copyflag`: `L_COPY`
+    pub fn add_boxa(&mut self, boxa: &Boxa) -> l_ok {
+        let boxa = AsRef::<leptonica_sys::Boxa>::as_ref(boxa) as *const _ as *mut _;
+        unsafe { boxaaAddBoxa(self.0, boxa, L_COPY.try_into().unwrap()) }
+    }
+
+    /// Wrapper for [`boxaaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/boxbasic.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { boxaaGetCount(self.0) }
+    }
+
+    /// Wrapper for [`boxaaGetBoxa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/boxbasic.c) with copied `accessflag`: `L_COPY`
+    pub fn get_boxa_copied(&self, index: l_int32) -> Option<RefCountedExclusive<Boxa>> {
+        unsafe {
+            boxaaGetBoxa(self.0, index, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Boxa::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`boxaaGetBoxa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/boxbasic.c) with cloned `accessflag`: `L_CLONE`
+    pub fn get_boxa_cloned(&self, index: l_int32) -> Option<RefCounted<Boxa>> {
+        unsafe {
+            boxaaGetBoxa(self.0, index, L_CLONE.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCounted::new(Boxa::new_from_pointer(raw)))
+        }
+    }
+
+    /// Iterates over clones of the inner Boxa, see [`Boxaa::get_boxa_cloned`]
+    pub fn iter_boxa_cloned(&self) -> impl Iterator<Item = RefCounted<Boxa>> + '_ {
+        (0..self.get_count()).filter_map(move |index| self.get_boxa_cloned(index))
+    }
+
+    /// Wrapper for [`boxaaFlattenToBoxa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/boxfunc2.c) with copied `copyflag`: `L_COPY`
+    ///
+    /// Returns all boxes in a single Boxa, with the index of the inner Boxa each came from.
+    pub fn flatten(&self) -> Option<(RefCountedExclusive<Boxa>, RefCountedExclusive<Numa>)> {
+        let mut naindex = std::ptr::null_mut();
+        let mut boxa =
+            unsafe { boxaaFlattenToBoxa(self.0, &mut naindex, L_COPY.try_into().unwrap()) };
+        if boxa.is_null() || naindex.is_null() {
+            unsafe {
+                boxaDestroy(&mut boxa);
+                numaDestroy(&mut naindex);
+            }
+            return None;
+        }
+        unsafe {
+            Some((
+                RefCountedExclusive::new(Boxa::new_from_pointer(boxa)),
+                RefCountedExclusive::new(Numa::new_from_pointer(naindex)),
+            ))
+        }
+    }
+}
+
+impl LeptonicaDestroy for Boxaa {
+    unsafe fn destroy(&mut self) {
+        boxaaDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Box;
+    use leptonica_sys::boxaAddBox;
+
+    fn boxa(boxes: &[(l_int32, l_int32, l_int32, l_int32)]) -> RefCountedExclusive<Boxa> {
+        let mut boxa = Boxa::create(boxes.len().try_into().unwrap()).unwrap();
+        for &(x, y, w, h) in boxes {
+            let mut b = Box::create_valid(x, y, w, h).unwrap();
+            unsafe {
+                boxaAddBox(boxa.as_mut(), b.as_mut(), L_COPY.try_into().unwrap());
+            }
+        }
+        boxa
+    }
+
+    #[test]
+    fn get_test() {
+        let mut boxaa = Boxaa::create(2).unwrap();
+        assert_eq!(boxaa.get_count(), 0);
+        boxaa.add_boxa(&boxa(&[(1, 2, 3, 4)]));
+        boxaa.add_boxa(&boxa(&[(5, 6, 7, 8), (9, 10, 11, 12)]));
+        assert_eq!(boxaa.get_count(), 2);
+
+        assert_eq!(boxaa.get_boxa_copied(0).unwrap().get_count(), 1);
+        assert_eq!(boxaa.get_boxa_cloned(1).unwrap().get_count(), 2);
+        assert!(boxaa.get_boxa_copied(2).is_none());
+        assert!(boxaa.get_boxa_cloned(2).is_none());
+
+        let counts: Vec<_> = boxaa.iter_boxa_cloned().map(|b| b.get_count()).collect();
+        assert_eq!(counts, [1, 2]);
+    }
+
+    #[test]
+    fn flatten_test() {
+        let mut boxaa = Boxaa::create(2).unwrap();
+        boxaa.add_boxa(&boxa(&[(1, 2, 3, 4)]));
+        boxaa.add_boxa(&boxa(&[(5, 6, 7, 8), (9, 10, 11, 12)]));
+
+        let (flat, naindex) = boxaa.flatten().unwrap();
+        assert_eq!(flat.get_count(), 3);
+        assert_eq!(naindex.to_vec(), [0.0, 1.0, 1.0]);
+
+        let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+        flat.get_box_copied(2).unwrap().get_geometry(
+            Some(&mut x),
+            Some(&mut y),
+            Some(&mut w),
+            Some(&mut h),
+        );
+        assert_eq!((x, y, w, h), (9, 10, 11, 12));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{memory::BorrowedFrom, Boxa, Numaa, Ptaa};
+
+/// Wrapper around Leptonica's [`CCBord`](https://tpgit.github.io/Leptonica/struct_c_c_bord.html) structure
+///
+/// The borders of a single connected component: the outer border first, then the
+/// border of each hole. Lives in a [`crate::CcBorda`].
+#[derive(Debug, PartialEq)]
+pub struct CcBord(*mut leptonica_sys::CCBord);
+
+impl AsRef<leptonica_sys::CCBord> for CcBord {
+    fn as_ref(&self) -> &leptonica_sys::CCBord {
+        unsafe { &*self.0 }
+    }
+}
+
+impl CcBord {
+    /// Create a new CcBord from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid CCBord struct.
+    /// The CCBord struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::CCBord) -> Self {
+        Self(p)
+    }
+
+    /// Get the bounding boxes of the component and its holes, in image coordinates
+    pub fn get_boxa(&self) -> Option<BorrowedFrom<'_, Boxa>> {
+        let ptr = AsRef::<leptonica_sys::CCBord>::as_ref(self).boxa;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Get the border pixels, relative to the bounding box of the component
+    pub fn get_local_borders(&self) -> Option<BorrowedFrom<'_, Ptaa>> {
+        Self::borrow_ptaa(AsRef::<leptonica_sys::CCBord>::as_ref(self).local)
+    }
+
+    /// Get the border pixels in image coordinates, once generated with
+    /// [`crate::CcBorda::generate_global_locs`]
+    pub fn get_global_borders(&self) -> Option<BorrowedFrom<'_, Ptaa>> {
+        Self::borrow_ptaa(AsRef::<leptonica_sys::CCBord>::as_ref(self).global)
+    }
+
+    /// Get the chain code of each border, once generated with
+    /// [`crate::CcBorda::generate_step_chains`]
+    ///
+    /// Each step is the direction to the next border pixel, from 0 to 7.
+    pub fn get_step_chains(&self) -> Option<BorrowedFrom<'_, Numaa>> {
+        let ptr = AsRef::<leptonica_sys::CCBord>::as_ref(self).step;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(Numaa::new_from_pointer(ptr)) })
+        }
+    }
+
+    fn borrow_ptaa<'a>(ptr: *mut leptonica_sys::Ptaa) -> Option<BorrowedFrom<'a, Ptaa>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(Ptaa::new_from_pointer(ptr)) })
+        }
+    }
+}
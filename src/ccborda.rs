@@ -0,0 +1,188 @@
+use crate::{
+    memory::{BorrowedFrom, LeptonicaDestroy, RefCountedExclusive},
+    CcBord, Pix, Str,
+};
+
+use leptonica_sys::{
+    ccbaDestroy, ccbaDisplayImage2, ccbaGenerateGlobalLocs, ccbaGenerateSPGlobalLocs,
+    ccbaGenerateSinglePath, ccbaGenerateStepChains, ccbaGetCount, ccbaWriteSVGString, l_int32,
+    pixGetAllCCBorders, CCB_SAVE_TURNING_PTS,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Wrapper around Leptonica's [`CCBorda`](https://tpgit.github.io/Leptonica/struct_c_c_borda.html) structure
+///
+/// The borders of all connected components of a 1 bpp image.
+#[derive(Debug, PartialEq)]
+pub struct CcBorda(*mut leptonica_sys::CCBorda);
+
+/// Error returned by CcBorda::create
+#[derive(Debug, Error)]
+#[error("CcBorda::create returned null")]
+pub struct CcBordaCreateError();
+
+/// Error returned by the CcBorda border generation methods
+#[derive(Debug, Error, PartialEq)]
+#[error("CcBorda border generation failed")]
+pub struct CcBordaGenerateError();
+
+impl AsRef<leptonica_sys::CCBorda> for CcBorda {
+    fn as_ref(&self) -> &leptonica_sys::CCBorda {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::CCBorda> for CcBorda {
+    fn as_mut(&mut self) -> &mut leptonica_sys::CCBorda {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl CcBorda {
+    /// Create a new CcBorda from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid CCBorda struct.
+    /// The CCBorda struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::CCBorda) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`pixGetAllCCBorders`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ccbord.c)
+    ///
+    /// Input: pixs (1 bpp). Finds the outer and hole borders of every 8-connected component.
+    pub fn create(pixs: &Pix) -> Result<RefCountedExclusive<Self>, CcBordaCreateError> {
+        let ptr = unsafe { pixGetAllCCBorders(*pixs.as_ref()) };
+        if ptr.is_null() {
+            Err(CcBordaCreateError())
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`ccbaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ccbord.c)
+    ///
+    /// Number of connected components.
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { ccbaGetCount(self.0) }
+    }
+
+    /// Get the borders of the connected component at `index`
+    pub fn get_ccb(&self, index: l_int32) -> Option<BorrowedFrom<'_, CcBord>> {
+        if index < 0 || index >= self.get_count() {
+            return None;
+        }
+        let index: usize = index.try_into().unwrap();
+        let ptr = unsafe { *AsRef::<leptonica_sys::CCBorda>::as_ref(self).ccb.add(index) };
+        Some(unsafe { BorrowedFrom::new(CcBord::new_from_pointer(ptr)) })
+    }
+
+    /// Wrapper for [`ccbaGenerateGlobalLocs`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ccbord.c)
+    ///
+    /// Generates the borders in image coordinates, see [`CcBord::get_global_borders`].
+    pub fn generate_global_locs(&mut self) -> Result<(), CcBordaGenerateError> {
+        if unsafe { ccbaGenerateGlobalLocs(self.0) } != 0 {
+            Err(CcBordaGenerateError())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`ccbaGenerateStepChains`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ccbord.c)
+    ///
+    /// Generates the chain codes of the borders, see [`CcBord::get_step_chains`].
+    pub fn generate_step_chains(&mut self) -> Result<(), CcBordaGenerateError> {
+        if unsafe { ccbaGenerateStepChains(self.0) } != 0 {
+            Err(CcBordaGenerateError())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`ccbaWriteSVGString`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ccbord.c)
+    ///
+    /// First joins the borders of each component into a single path of its turning points
+    /// with `ccbaGenerateSinglePath` and `ccbaGenerateSPGlobalLocs`.
+    pub fn write_svg_string(&mut self) -> Option<Str> {
+        unsafe {
+            if ccbaGenerateSinglePath(self.0) != 0
+                || ccbaGenerateSPGlobalLocs(self.0, CCB_SAVE_TURNING_PTS.try_into().unwrap()) != 0
+            {
+                return None;
+            }
+            let ptr = ccbaWriteSVGString(self.0);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(Str::new_from_pointer(ptr))
+            }
+        }
+    }
+
+    /// Wrapper for [`ccbaDisplayImage2`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ccbord.c)
+    ///
+    /// Reconstructs the 1 bpp image from the borders.
+    pub fn display_image2(&self) -> Option<RefCountedExclusive<Pix>> {
+        let ptr = unsafe { ccbaDisplayImage2(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for CcBorda {
+    unsafe fn destroy(&mut self) {
+        ccbaDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ring_image;
+
+    #[test]
+    fn borders_test() {
+        let pix = ring_image();
+        let mut ccba = CcBorda::create(&pix).unwrap();
+        assert_eq!(ccba.get_count(), 1);
+        assert!(ccba.get_ccb(1).is_none());
+        {
+            let ccb = ccba.get_ccb(0).unwrap();
+            // The outer border and the border of the hole
+            assert_eq!(ccb.get_local_borders().unwrap().get_count(), 2);
+            assert_eq!(ccb.get_boxa().unwrap().get_count(), 2);
+            assert!(ccb.get_global_borders().is_none());
+            assert!(ccb.get_step_chains().is_none());
+        }
+
+        assert_eq!(ccba.generate_global_locs(), Ok(()));
+        assert_eq!(ccba.generate_step_chains(), Ok(()));
+        let ccb = ccba.get_ccb(0).unwrap();
+        let outer = ccb.get_global_borders().unwrap().get_pta_copied(0).unwrap();
+        assert_eq!(outer.get_ipt(0), Some((1, 1)));
+        let steps = ccb.get_step_chains().unwrap();
+        assert_eq!(steps.get_count(), 2);
+        assert!(steps.get_numa_copied(0).unwrap().get_count() > 0);
+    }
+
+    #[test]
+    fn svg_test() {
+        let pix = ring_image();
+        let mut ccba = CcBorda::create(&pix).unwrap();
+        let svg = ccba.write_svg_string().unwrap();
+        assert!(svg.to_str().unwrap().contains("<svg"));
+    }
+
+    #[test]
+    fn display_image2_test() {
+        let pix = ring_image();
+        let ccba = CcBorda::create(&pix).unwrap();
+        let restored = ccba.display_image2().unwrap();
+        assert!(*restored == *pix);
+    }
+}
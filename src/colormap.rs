@@ -0,0 +1,69 @@
+use crate::Rgb;
+
+use leptonica_sys::{l_int32, pixcmapGetColor, pixcmapGetCount, pixcmapGetDepth};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`PixColormap`](https://tpgit.github.io/Leptonica/struct_pix_colormap.html) structure
+///
+/// The palette of a colormapped Pix.
+#[derive(Debug, PartialEq)]
+pub struct PixColormap(*mut leptonica_sys::PixColormap);
+
+impl AsRef<*mut leptonica_sys::PixColormap> for PixColormap {
+    fn as_ref(&self) -> &*mut leptonica_sys::PixColormap {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::PixColormap> for PixColormap {
+    fn as_ref(&self) -> &leptonica_sys::PixColormap {
+        unsafe { &*self.0 }
+    }
+}
+
+impl PixColormap {
+    /// Create a new PixColormap from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid PixColormap struct.
+    /// The PixColormap struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::PixColormap) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`pixcmapGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colormap.c)
+    ///
+    /// Number of colors in the colormap.
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { pixcmapGetCount(self.0) }
+    }
+
+    /// Wrapper for [`pixcmapGetDepth`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colormap.c)
+    ///
+    /// Depth of the pix the colormap is for (2, 4 or 8).
+    pub fn get_depth(&self) -> l_int32 {
+        unsafe { pixcmapGetDepth(self.0) }
+    }
+
+    /// Wrapper for [`pixcmapGetColor`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colormap.c)
+    pub fn get_color(&self, index: l_int32) -> Option<Rgb> {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        if unsafe { pixcmapGetColor(self.0, index, &mut r, &mut g, &mut b) } != 0 {
+            None
+        } else {
+            Some(Rgb::new(
+                r.try_into().unwrap(),
+                g.try_into().unwrap(),
+                b.try_into().unwrap(),
+            ))
+        }
+    }
+
+    /// All colors, in index order
+    pub fn get_colors(&self) -> Vec<Rgb> {
+        (0..self.get_count())
+            .filter_map(|i| self.get_color(i))
+            .collect()
+    }
+}
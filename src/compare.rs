@@ -0,0 +1,345 @@
+//! Image comparison and similarity metrics
+
+use crate::{memory::RefCountedExclusive, Box, Pix};
+
+use leptonica_sys::{
+    l_float32, l_int32, pixCompareGray, pixCompareGrayByHisto, pixCompareRGB, pixCorrelationBinary,
+    pixDestroy, pixEqual, pixGetExtremeValue, pixGetPSNR, L_COMPARE_ABS_DIFF, L_COMPARE_SUBTRACT,
+    L_SELECT_MAX,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the comparison functions
+#[derive(Debug, Error, PartialEq)]
+pub enum CompareError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix comparison failed")]
+    Failed,
+}
+
+/// How the difference of two images is computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareType {
+    /// `L_COMPARE_SUBTRACT`: `pix1 - pix2`, clipped to 0
+    Subtract,
+    /// `L_COMPARE_ABS_DIFF`: `|pix1 - pix2|`
+    AbsDiff,
+}
+
+impl CompareType {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Subtract => L_COMPARE_SUBTRACT,
+            Self::AbsDiff => L_COMPARE_ABS_DIFF,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+/// Result of [`compare_gray`] or [`compare_rgb`]
+#[derive(Debug)]
+pub struct Comparison {
+    /// Whether any pixel differs
+    pub differs: bool,
+    /// Largest difference of any pixel (or any component of an RGB pixel)
+    pub max_diff: l_int32,
+    /// Average difference of all pixels
+    pub mean_diff: l_float32,
+    /// Root mean square difference of all pixels
+    pub rms_diff: l_float32,
+    /// Peak signal to noise ratio in dB, see [`psnr`]. `None` for 16 bpp images.
+    pub psnr: Option<l_float32>,
+    /// The difference image, if requested
+    pub diff: Option<RefCountedExclusive<Pix>>,
+}
+
+/// Wrapper for [`pixEqual`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/compare.c)
+///
+/// Whether the two images have the same size and pixel values.
+pub fn equal(pix1: &Pix, pix2: &Pix) -> Result<bool, CompareError> {
+    let mut same = 0;
+    if unsafe { pixEqual(*pix1.as_ref(), *pix2.as_ref(), &mut same) } != 0 {
+        Err(CompareError::Failed)
+    } else {
+        Ok(same != 0)
+    }
+}
+
+/// Wrapper for [`pixCorrelationBinary`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/compare.c)
+///
+/// Input: 1 bpp. Returns the correlation of the foregrounds, between 0.0 and 1.0.
+pub fn correlation_binary(pix1: &Pix, pix2: &Pix) -> Result<l_float32, CompareError> {
+    pix1.check_depth(&[1], CompareError::UnsupportedDepth)?;
+    pix2.check_depth(&[1], CompareError::UnsupportedDepth)?;
+    let mut val = 0.0;
+    if unsafe { pixCorrelationBinary(*pix1.as_ref(), *pix2.as_ref(), &mut val) } != 0 {
+        Err(CompareError::Failed)
+    } else {
+        Ok(val)
+    }
+}
+
+/// Wrapper for [`pixCompareGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/compare.c)
+///
+/// Input: 8 or 16 bpp without a colormap. Set `with_diff` to keep the difference image.
+pub fn compare_gray(
+    pix1: &Pix,
+    pix2: &Pix,
+    comptype: CompareType,
+    with_diff: bool,
+) -> Result<Comparison, CompareError> {
+    pix1.check_depth(&[8, 16], CompareError::UnsupportedDepth)?;
+    pix2.check_depth(&[8, 16], CompareError::UnsupportedDepth)?;
+    compare(pix1, pix2, comptype, with_diff, pixCompareGray)
+}
+
+/// Wrapper for [`pixCompareRGB`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/compare.c)
+///
+/// Input: 32 bpp. Set `with_diff` to keep the difference image.
+pub fn compare_rgb(
+    pix1: &Pix,
+    pix2: &Pix,
+    comptype: CompareType,
+    with_diff: bool,
+) -> Result<Comparison, CompareError> {
+    pix1.check_depth(&[32], CompareError::UnsupportedDepth)?;
+    pix2.check_depth(&[32], CompareError::UnsupportedDepth)?;
+    compare(pix1, pix2, comptype, with_diff, pixCompareRGB)
+}
+
+/// Wrapper for [`pixGetPSNR`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/compare.c)
+///
+/// Input: 8 or 32 bpp, factor (sampling factor, 1 for every pixel).
+/// Returns the peak signal to noise ratio in dB, or 1000.0 for identical images.
+pub fn psnr(pix1: &Pix, pix2: &Pix, factor: l_int32) -> Result<l_float32, CompareError> {
+    pix1.check_depth(&[8, 32], CompareError::UnsupportedDepth)?;
+    pix2.check_depth(&[8, 32], CompareError::UnsupportedDepth)?;
+    let mut psnr = 0.0;
+    if unsafe { pixGetPSNR(*pix1.as_ref(), *pix2.as_ref(), factor, &mut psnr) } != 0 {
+        Err(CompareError::Failed)
+    } else {
+        Ok(psnr)
+    }
+}
+
+/// Wrapper for [`pixCompareGrayByHisto`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/compare.c)
+///
+/// Compares the gray histograms of an `n` by `n` grid of tiles of the (optionally boxed) regions.
+/// Input: minratio (minimum allowed ratio of the region sizes, eg 0.5), maxgray (pixels
+/// above this are ignored), factor (sampling factor), n (1 to 7).
+/// Returns a similarity score between 0.0 and 1.0.
+#[allow(clippy::too_many_arguments)]
+pub fn compare_gray_by_histo(
+    pix1: &Pix,
+    pix2: &Pix,
+    box1: Option<&Box>,
+    box2: Option<&Box>,
+    minratio: l_float32,
+    maxgray: l_int32,
+    factor: l_int32,
+    n: l_int32,
+) -> Result<l_float32, CompareError> {
+    let box_ptr = |r#box: Option<&Box>| match r#box {
+        None => std::ptr::null_mut(),
+        Some(r#box) => AsRef::<leptonica_sys::Box>::as_ref(r#box) as *const _ as *mut _,
+    };
+    let mut score = 0.0;
+    let result = unsafe {
+        pixCompareGrayByHisto(
+            *pix1.as_ref(),
+            *pix2.as_ref(),
+            box_ptr(box1),
+            box_ptr(box2),
+            minratio,
+            maxgray,
+            factor,
+            n,
+            &mut score,
+            0,
+        )
+    };
+    if result != 0 {
+        Err(CompareError::Failed)
+    } else {
+        Ok(score)
+    }
+}
+
+type CompareFn = unsafe extern "C" fn(
+    *mut leptonica_sys::Pix,
+    *mut leptonica_sys::Pix,
+    l_int32,
+    l_int32,
+    *mut l_int32,
+    *mut l_float32,
+    *mut l_float32,
+    *mut *mut leptonica_sys::Pix,
+) -> l_int32;
+
+fn compare(
+    pix1: &Pix,
+    pix2: &Pix,
+    comptype: CompareType,
+    with_diff: bool,
+    compare_fn: CompareFn,
+) -> Result<Comparison, CompareError> {
+    let (mut same, mut mean_diff, mut rms_diff) = (0, 0.0, 0.0);
+    let mut pixdiff = std::ptr::null_mut();
+    let result = unsafe {
+        compare_fn(
+            *pix1.as_ref(),
+            *pix2.as_ref(),
+            comptype.to_leptonica(),
+            0,
+            &mut same,
+            &mut mean_diff,
+            &mut rms_diff,
+            &mut pixdiff,
+        )
+    };
+    if result != 0 || pixdiff.is_null() {
+        unsafe { pixDestroy(&mut pixdiff) };
+        return Err(CompareError::Failed);
+    }
+    let diff = unsafe { RefCountedExclusive::new(Pix::new_from_pointer(pixdiff)) };
+    let max_diff = max_value(&diff)?;
+    Ok(Comparison {
+        differs: same == 0,
+        max_diff,
+        mean_diff,
+        rms_diff,
+        psnr: if pix1.get_depth() == 16 {
+            None
+        } else {
+            Some(psnr(pix1, pix2, 1)?)
+        },
+        diff: if with_diff { Some(diff) } else { None },
+    })
+}
+
+fn max_value(pix: &Pix) -> Result<l_int32, CompareError> {
+    if pix.get_depth() == 16 {
+        let max = (0..pix.get_height())
+            .flat_map(|y| (0..pix.get_width()).map(move |x| (x, y)))
+            .filter_map(|(x, y)| pix.get_pixel(x, y))
+            .max()
+            .unwrap_or(0);
+        return Ok(max.try_into().unwrap());
+    }
+    let (mut r, mut g, mut b, mut gray) = (0, 0, 0, 0);
+    let result = unsafe {
+        pixGetExtremeValue(
+            *pix.as_ref(),
+            1,
+            L_SELECT_MAX.try_into().unwrap(),
+            &mut r,
+            &mut g,
+            &mut b,
+            &mut gray,
+        )
+    };
+    if result != 0 {
+        Err(CompareError::Failed)
+    } else if pix.get_depth() == 32 {
+        Ok(r.max(g).max(b))
+    } else {
+        Ok(gray)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_image(values: &[u32]) -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(values.len() as l_int32, 1, 8).unwrap();
+        for (x, &val) in values.iter().enumerate() {
+            pix.set_pixel(x as l_int32, 0, val);
+        }
+        pix
+    }
+
+    #[test]
+    fn equal_test() {
+        let pix1 = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let mut pix2 = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        assert_eq!(equal(&pix1, &pix2), Ok(true));
+        assert!(*pix1 == *pix2);
+        let val = pix2.get_pixel(0, 0).unwrap();
+        pix2.set_pixel(0, 0, !val & 0xffffff00);
+        assert_eq!(equal(&pix1, &pix2), Ok(false));
+        assert!(*pix1 != *pix2);
+    }
+
+    #[test]
+    fn correlation_binary_test() {
+        let mut pix1 = Pix::create(4, 4, 1).unwrap();
+        pix1.set_pixel(1, 1, 1);
+        pix1.set_pixel(2, 2, 1);
+        let mut pix2 = Pix::create(4, 4, 1).unwrap();
+        pix2.set_pixel(1, 1, 1);
+        pix2.set_pixel(2, 2, 1);
+        assert_eq!(correlation_binary(&pix1, &pix2), Ok(1.0));
+        let gray = row_image(&[0]);
+        assert_eq!(
+            correlation_binary(&pix1, &gray),
+            Err(CompareError::UnsupportedDepth(8))
+        );
+    }
+
+    #[test]
+    fn compare_gray_test() {
+        let pix1 = row_image(&[10, 20]);
+        let pix2 = row_image(&[10, 25]);
+        let comparison = compare_gray(&pix1, &pix2, CompareType::AbsDiff, true).unwrap();
+        assert!(comparison.differs);
+        assert_eq!(comparison.max_diff, 5);
+        assert!((comparison.mean_diff - 2.5).abs() < 0.001);
+        assert_eq!(comparison.psnr, Some(psnr(&pix1, &pix2, 1).unwrap()));
+        assert_eq!(comparison.diff.unwrap().get_pixel(1, 0), Some(5));
+
+        let comparison = compare_gray(&pix1, &pix1, CompareType::Subtract, false).unwrap();
+        assert!(!comparison.differs);
+        assert_eq!(comparison.max_diff, 0);
+        assert_eq!(comparison.psnr, Some(1000.0));
+        assert!(comparison.diff.is_none());
+
+        let pix16 = Pix::create(2, 1, 16).unwrap();
+        let comparison = compare_gray(&pix16, &pix16, CompareType::AbsDiff, false).unwrap();
+        assert!(!comparison.differs);
+        assert_eq!(comparison.psnr, None);
+    }
+
+    #[test]
+    fn compare_rgb_test() {
+        let pix1 = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let pix2 = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let comparison = compare_rgb(&pix1, &pix2, CompareType::AbsDiff, false).unwrap();
+        assert!(!comparison.differs);
+        assert_eq!(comparison.mean_diff, 0.0);
+        assert_eq!(comparison.psnr, Some(1000.0));
+        assert_eq!(
+            compare_rgb(&pix1, &row_image(&[0]), CompareType::AbsDiff, false).err(),
+            Some(CompareError::UnsupportedDepth(8))
+        );
+    }
+
+    #[test]
+    fn psnr_test() {
+        let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        assert_eq!(psnr(&pix, &pix, 1), Ok(1000.0));
+        let pix1 = row_image(&[10, 20]);
+        let pix2 = row_image(&[10, 25]);
+        assert!(psnr(&pix1, &pix2, 1).unwrap() < 1000.0);
+    }
+
+    #[test]
+    fn compare_gray_by_histo_test() {
+        let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let score = compare_gray_by_histo(&pix, &pix, None, None, 0.5, 255, 1, 1).unwrap();
+        assert!(score > 0.9);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    memory::{BorrowedFrom, LeptonicaDestroy, RefCountedExclusive},
+    Fpix, Pix,
+};
+
+use leptonica_sys::{dewarpCreate, dewarpDestroy, l_int32, pixCopy, pixDestroy};
+use thiserror::Error;
+
+/// Wrapper around Leptonica's [`L_Dewarp`](https://tpgit.github.io/Leptonica/struct_l___dewarp.html) structure
+///
+/// Holds the disparity model of a single page.
+/// Once built, the model lives in a [`crate::Dewarpa`].
+#[derive(Debug, PartialEq)]
+pub struct Dewarp(*mut leptonica_sys::L_Dewarp);
+
+/// Error returned by Dewarp::create
+#[derive(Debug, Error)]
+#[error("Dewarp::create returned null")]
+pub struct DewarpCreateError();
+
+impl AsRef<leptonica_sys::L_Dewarp> for Dewarp {
+    fn as_ref(&self) -> &leptonica_sys::L_Dewarp {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::L_Dewarp> for Dewarp {
+    fn as_mut(&mut self) -> &mut leptonica_sys::L_Dewarp {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Dewarp {
+    /// Create a new Dewarp from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid L_Dewarp struct.
+    /// The L_Dewarp struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::L_Dewarp) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`dewarpCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    ///
+    /// Input: pixs (1 bpp), pageno. The model keeps its own copy of `pixs`, and is empty until
+    /// it is inserted into a [`crate::Dewarpa`] and built with [`crate::Dewarpa::build_page_model`].
+    pub fn create(
+        pixs: &Pix,
+        pageno: l_int32,
+    ) -> Result<RefCountedExclusive<Self>, DewarpCreateError> {
+        let mut copy = unsafe {
+            pixCopy(
+                std::ptr::null_mut(),
+                AsRef::<leptonica_sys::Pix>::as_ref(pixs),
+            )
+        };
+        if copy.is_null() {
+            return Err(DewarpCreateError());
+        }
+        let ptr = unsafe { dewarpCreate(copy, pageno) };
+        unsafe { pixDestroy(&mut copy) };
+        if ptr.is_null() {
+            Err(DewarpCreateError())
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Get the page number this model was created for
+    pub fn get_pageno(&self) -> l_int32 {
+        AsRef::<leptonica_sys::L_Dewarp>::as_ref(self).pageno
+    }
+
+    /// Get the vertical disparity array at sampled resolution, if it has been built
+    pub fn get_sampled_vertical_disparity(&self) -> Option<BorrowedFrom<'_, Fpix>> {
+        Self::borrow_fpix(AsRef::<leptonica_sys::L_Dewarp>::as_ref(self).sampvdispar)
+    }
+
+    /// Get the horizontal disparity array at sampled resolution, if it has been built
+    pub fn get_sampled_horizontal_disparity(&self) -> Option<BorrowedFrom<'_, Fpix>> {
+        Self::borrow_fpix(AsRef::<leptonica_sys::L_Dewarp>::as_ref(self).samphdispar)
+    }
+
+    /// Get the vertical disparity array at full resolution, if the model has been applied
+    pub fn get_full_vertical_disparity(&self) -> Option<BorrowedFrom<'_, Fpix>> {
+        Self::borrow_fpix(AsRef::<leptonica_sys::L_Dewarp>::as_ref(self).fullvdispar)
+    }
+
+    /// Get the horizontal disparity array at full resolution, if the model has been applied
+    pub fn get_full_horizontal_disparity(&self) -> Option<BorrowedFrom<'_, Fpix>> {
+        Self::borrow_fpix(AsRef::<leptonica_sys::L_Dewarp>::as_ref(self).fullhdispar)
+    }
+
+    fn borrow_fpix<'a>(ptr: *mut leptonica_sys::FPix) -> Option<BorrowedFrom<'a, Fpix>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(Fpix::new_from_pointer(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for Dewarp {
+    unsafe fn destroy(&mut self) {
+        dewarpDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::binary_text_image;
+
+    #[test]
+    fn create_test() {
+        let pix = binary_text_image();
+        let dew = Dewarp::create(&pix, 3).unwrap();
+        assert_eq!(dew.get_pageno(), 3);
+        assert!(dew.get_sampled_vertical_disparity().is_none());
+        assert!(dew.get_full_horizontal_disparity().is_none());
+    }
+
+    #[test]
+    fn create_wrong_depth_test() {
+        let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        assert!(Dewarp::create(&pix, 0).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{
+    memory::{BorrowedFrom, LeptonicaDestroy, RefCountedExclusive},
+    Dewarp, Pix,
+};
+
+use leptonica_sys::{
+    dewarpBuildPageModel, dewarpSinglePage, dewarpaApplyDisparity, dewarpaCreate, dewarpaDestroy,
+    dewarpaGetDewarp, dewarpaInsertDewarp, dewarpaInsertRefModels, dewarpaModelStatus,
+    dewarpaSetCheckColumns, dewarpaUseBothArrays, l_int32, pixCopy, pixDestroy,
+};
+use thiserror::Error;
+
+/// Wrapper around Leptonica's [`L_Dewarpa`](https://tpgit.github.io/Leptonica/struct_l___dewarpa.html) structure
+///
+/// A collection of page models, indexed by page number, for dewarping the pages of a book.
+#[derive(Debug, PartialEq)]
+pub struct Dewarpa(*mut leptonica_sys::L_Dewarpa);
+
+/// Error returned by Dewarpa::create
+#[derive(Debug, Error)]
+#[error("Dewarpa::create returned null")]
+pub struct DewarpaCreateError();
+
+/// Error returned by the Dewarpa model building and applying methods
+#[derive(Debug, Error, PartialEq)]
+pub enum DewarpaError {
+    #[error("Failed to insert the dewarp model")]
+    InsertDewarp,
+    #[error("No dewarp model for page {0}")]
+    NoModel(l_int32),
+    #[error("Failed to build the page model")]
+    BuildPageModel,
+    #[error("Failed to insert the reference models")]
+    InsertRefModels,
+    #[error("Failed to apply the disparity")]
+    ApplyDisparity,
+    #[error("Failed to dewarp the single page")]
+    SinglePage,
+}
+
+impl AsRef<leptonica_sys::L_Dewarpa> for Dewarpa {
+    fn as_ref(&self) -> &leptonica_sys::L_Dewarpa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::L_Dewarpa> for Dewarpa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::L_Dewarpa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Dewarpa {
+    /// Create a new Dewarpa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid L_Dewarpa struct.
+    /// The L_Dewarpa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::L_Dewarpa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`dewarpaCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    ///
+    /// Input: nptrs (number of pages), sampling (use 0 for default), redfactor (1 or 2),
+    /// minlines (use 0 for default), maxdist (use -1 for default)
+    pub fn create(
+        nptrs: l_int32,
+        sampling: l_int32,
+        redfactor: l_int32,
+        minlines: l_int32,
+        maxdist: l_int32,
+    ) -> Result<RefCountedExclusive<Self>, DewarpaCreateError> {
+        let ptr = unsafe { dewarpaCreate(nptrs, sampling, redfactor, minlines, maxdist) };
+        if ptr.is_null() {
+            Err(DewarpaCreateError())
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`dewarpSinglePage`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp4.c)
+    ///
+    /// Builds a model for `pixs` and applies it.
+    /// Returns the dewarped image and the Dewarpa holding the model.
+    pub fn single_page(
+        pixs: &Pix,
+        thresh: l_int32,
+        adaptive: bool,
+        useboth: bool,
+        check_columns: bool,
+    ) -> Result<(RefCountedExclusive<Pix>, RefCountedExclusive<Self>), DewarpaError> {
+        let mut pixd = std::ptr::null_mut();
+        let mut dewa = std::ptr::null_mut();
+        let result = unsafe {
+            dewarpSinglePage(
+                *pixs.as_ref(),
+                thresh,
+                adaptive.into(),
+                useboth.into(),
+                check_columns.into(),
+                &mut pixd,
+                &mut dewa,
+                0,
+            )
+        };
+        let dewa = if dewa.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(dewa)) })
+        };
+        if result != 0 || pixd.is_null() {
+            unsafe { pixDestroy(&mut pixd) };
+            return Err(DewarpaError::SinglePage);
+        }
+        let pixd = unsafe { Self::exclusive_pix(pixs, pixd) };
+        match dewa {
+            Some(dewa) => Ok((pixd, dewa)),
+            None => Err(DewarpaError::SinglePage),
+        }
+    }
+
+    /// Wrapper for [`dewarpaInsertDewarp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    ///
+    /// The Dewarpa takes ownership of the model, replacing any existing model for the same page.
+    pub fn insert_dewarp(
+        &mut self,
+        mut dew: RefCountedExclusive<Dewarp>,
+    ) -> Result<(), DewarpaError> {
+        if unsafe { dewarpaInsertDewarp(self.0, dew.as_mut()) } != 0 {
+            return Err(DewarpaError::InsertDewarp);
+        }
+        std::mem::forget(dew);
+        Ok(())
+    }
+
+    /// Wrapper for [`dewarpaGetDewarp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    pub fn get_dewarp(&self, pageno: l_int32) -> Option<BorrowedFrom<'_, Dewarp>> {
+        unsafe {
+            dewarpaGetDewarp(self.0, pageno)
+                .as_mut()
+                .map(|raw| BorrowedFrom::new(Dewarp::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`dewarpBuildPageModel`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp2.c)
+    ///
+    /// Builds the model of the inserted page `pageno`.
+    pub fn build_page_model(&mut self, pageno: l_int32) -> Result<(), DewarpaError> {
+        let dew = unsafe { dewarpaGetDewarp(self.0, pageno) };
+        if dew.is_null() {
+            return Err(DewarpaError::NoModel(pageno));
+        }
+        if unsafe { dewarpBuildPageModel(dew, std::ptr::null()) } != 0 {
+            Err(DewarpaError::BuildPageModel)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`dewarpaModelStatus`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp4.c)
+    ///
+    /// Returns whether the vertical and horizontal models of page `pageno` were built.
+    pub fn model_status(&self, pageno: l_int32) -> Option<(bool, bool)> {
+        let (mut vsuccess, mut hsuccess) = (0, 0);
+        if unsafe { dewarpaModelStatus(self.0, pageno, &mut vsuccess, &mut hsuccess) } != 0 {
+            None
+        } else {
+            Some((vsuccess != 0, hsuccess != 0))
+        }
+    }
+
+    /// Wrapper for [`dewarpaInsertRefModels`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    ///
+    /// Pages without a valid model reuse the model of the nearest page that has one.
+    pub fn insert_ref_models(&mut self, notests: bool) -> Result<(), DewarpaError> {
+        if unsafe { dewarpaInsertRefModels(self.0, notests.into(), 0) } != 0 {
+            Err(DewarpaError::InsertRefModels)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`dewarpaUseBothArrays`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    pub fn use_both_arrays(&mut self, useboth: bool) {
+        unsafe { dewarpaUseBothArrays(self.0, useboth.into()) };
+    }
+
+    /// Wrapper for [`dewarpaSetCheckColumns`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp1.c)
+    pub fn set_check_columns(&mut self, check_columns: bool) {
+        unsafe { dewarpaSetCheckColumns(self.0, check_columns.into()) };
+    }
+
+    /// Wrapper for [`dewarpaApplyDisparity`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/dewarp3.c)
+    ///
+    /// Dewarps `pixs` with the model (or reference model) of page `pageno`.
+    /// Use -1 for `grayin` to bring in pixels from the image boundary.
+    pub fn apply_disparity(
+        &mut self,
+        pageno: l_int32,
+        pixs: &Pix,
+        grayin: l_int32,
+        x: l_int32,
+        y: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, DewarpaError> {
+        let mut pixd = std::ptr::null_mut();
+        let result = unsafe {
+            dewarpaApplyDisparity(
+                self.0,
+                pageno,
+                *pixs.as_ref(),
+                grayin,
+                x,
+                y,
+                &mut pixd,
+                std::ptr::null(),
+            )
+        };
+        if result != 0 || pixd.is_null() {
+            unsafe { pixDestroy(&mut pixd) };
+            Err(DewarpaError::ApplyDisparity)
+        } else {
+            Ok(unsafe { Self::exclusive_pix(pixs, pixd) })
+        }
+    }
+
+    /// When no model applies, Leptonica can hand back a clone of the input.
+    /// Copy it so that the result can be safely mutated.
+    unsafe fn exclusive_pix(
+        pixs: &Pix,
+        mut pixd: *mut leptonica_sys::Pix,
+    ) -> RefCountedExclusive<Pix> {
+        if pixd == *pixs.as_ref() {
+            let copy = pixCopy(std::ptr::null_mut(), pixd);
+            pixDestroy(&mut pixd);
+            pixd = copy;
+        }
+        RefCountedExclusive::new(Pix::new_from_pointer(pixd))
+    }
+}
+
+impl LeptonicaDestroy for Dewarpa {
+    unsafe fn destroy(&mut self) {
+        dewarpaDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{binary_text_image, text_page};
+
+    #[test]
+    fn insert_dewarp_test() {
+        let mut dewa = Dewarpa::create(2, 0, 1, 0, -1).unwrap();
+        assert!(dewa.get_dewarp(1).is_none());
+        let dew = Dewarp::create(&binary_text_image(), 1).unwrap();
+        dewa.insert_dewarp(dew).unwrap();
+        assert_eq!(dewa.get_dewarp(1).unwrap().get_pageno(), 1);
+    }
+
+    #[test]
+    fn build_page_model_missing_test() {
+        let mut dewa = Dewarpa::create(2, 0, 1, 0, -1).unwrap();
+        assert_eq!(dewa.build_page_model(0), Err(DewarpaError::NoModel(0)));
+    }
+
+    #[test]
+    fn build_page_model_too_few_lines_test() {
+        let mut dewa = Dewarpa::create(1, 0, 1, 0, -1).unwrap();
+        dewa.insert_dewarp(Dewarp::create(&binary_text_image(), 0).unwrap())
+            .unwrap();
+        // A single line of text is not enough to model the curvature
+        assert_eq!(dewa.build_page_model(0), Err(DewarpaError::BuildPageModel));
+        assert_eq!(dewa.model_status(0), Some((false, false)));
+        assert!(dewa
+            .get_dewarp(0)
+            .unwrap()
+            .get_sampled_vertical_disparity()
+            .is_none());
+    }
+
+    #[test]
+    fn build_page_model_test() {
+        let page = text_page();
+        let mut dewa = Dewarpa::create(1, 0, 1, 0, -1).unwrap();
+        dewa.insert_dewarp(Dewarp::create(&page, 0).unwrap())
+            .unwrap();
+        dewa.build_page_model(0).unwrap();
+        let (vsuccess, _) = dewa.model_status(0).unwrap();
+        assert!(vsuccess);
+        {
+            let dew = dewa.get_dewarp(0).unwrap();
+            let disparity = dew.get_sampled_vertical_disparity().unwrap();
+            assert!(disparity.get_width() > 1);
+            assert!(disparity.get_height() > 1);
+            assert!(dew.get_full_vertical_disparity().is_none());
+        }
+
+        let dewarped = dewa.apply_disparity(0, &page, -1, 0, 0).unwrap();
+        assert_eq!(dewarped.get_width(), page.get_width());
+        assert_eq!(dewarped.get_height(), page.get_height());
+        let dew = dewa.get_dewarp(0).unwrap();
+        let disparity = dew.get_full_vertical_disparity().unwrap();
+        assert!(disparity.get_width() >= page.get_width());
+        assert!(disparity.get_height() >= page.get_height());
+    }
+
+    #[test]
+    fn single_page_test() {
+        let page = text_page();
+        let (dewarped, dewa) = Dewarpa::single_page(&page, 130, true, false, false).unwrap();
+        assert_eq!(dewarped.get_width(), page.get_width());
+        assert_eq!(dewarped.get_height(), page.get_height());
+        assert_eq!(dewa.model_status(0).map(|(v, _)| v), Some(true));
+        assert!(dewa
+            .get_dewarp(0)
+            .unwrap()
+            .get_sampled_vertical_disparity()
+            .is_some());
+    }
+}
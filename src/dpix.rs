@@ -0,0 +1,207 @@
+use crate::{
+    memory::{LeptonicaClone, LeptonicaDestroy, RefCountedExclusive},
+    NegativeValues, OutDepth, Pix,
+};
+
+use leptonica_sys::{
+    dpixAddMultConstant, dpixClone, dpixConvertToPix, dpixCreate, dpixDestroy, dpixGetData,
+    dpixGetDimensions, dpixGetWpl, dpixLinearCombination, l_float32, l_float64, l_int32,
+};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`DPix`](https://tpgit.github.io/Leptonica/struct_d_pix.html) structure
+#[derive(Debug, PartialEq)]
+pub struct Dpix(*mut leptonica_sys::DPix);
+
+impl AsRef<leptonica_sys::DPix> for Dpix {
+    fn as_ref(&self) -> &leptonica_sys::DPix {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::DPix> for Dpix {
+    fn as_mut(&mut self) -> &mut leptonica_sys::DPix {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Dpix {
+    /// Create a new Dpix from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid DPix struct.
+    /// The DPix struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::DPix) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`dpixCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// Input: width, height. The data is initialized to 0.
+    pub fn create(width: l_int32, height: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { dpixCreate(width, height) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`dpixGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// Returns the width and height.
+    pub fn get_dimensions(&self) -> (l_int32, l_int32) {
+        let (mut w, mut h) = (0, 0);
+        unsafe { dpixGetDimensions(self.0, &mut w, &mut h) };
+        (w, h)
+    }
+
+    /// Wrapper for [`dpixGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c) returning only the width
+    pub fn get_width(&self) -> l_int32 {
+        self.get_dimensions().0
+    }
+
+    /// Wrapper for [`dpixGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c) returning only the height
+    pub fn get_height(&self) -> l_int32 {
+        self.get_dimensions().1
+    }
+
+    /// Wrapper for [`dpixGetWpl`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// Number of values per line, including any padding.
+    pub fn get_wpl(&self) -> l_int32 {
+        unsafe { dpixGetWpl(self.0) }
+    }
+
+    /// Wrapper for [`dpixGetData`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// All rows, each of length `get_wpl()`.
+    pub fn get_data(&self) -> &[l_float64] {
+        let len = (self.get_wpl() * self.get_height()).try_into().unwrap();
+        unsafe { std::slice::from_raw_parts(dpixGetData(self.0), len) }
+    }
+
+    /// Wrapper for [`dpixGetData`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// All rows, each of length `get_wpl()`.
+    pub fn get_data_mut(&mut self) -> &mut [l_float64] {
+        let len = (self.get_wpl() * self.get_height()).try_into().unwrap();
+        unsafe { std::slice::from_raw_parts_mut(dpixGetData(self.0), len) }
+    }
+
+    /// The values of row `y`, or `None` if out of bounds
+    pub fn get_row(&self, y: l_int32) -> Option<&[l_float64]> {
+        let (start, end) = self.row_range(y)?;
+        Some(&self.get_data()[start..end])
+    }
+
+    /// The values of row `y`, or `None` if out of bounds
+    pub fn get_row_mut(&mut self, y: l_int32) -> Option<&mut [l_float64]> {
+        let (start, end) = self.row_range(y)?;
+        Some(&mut self.get_data_mut()[start..end])
+    }
+
+    fn row_range(&self, y: l_int32) -> Option<(usize, usize)> {
+        if y < 0 || y >= self.get_height() {
+            return None;
+        }
+        let start: usize = (y * self.get_wpl()).try_into().unwrap();
+        let width: usize = self.get_width().try_into().unwrap();
+        Some((start, start + width))
+    }
+
+    /// Wrapper for [`dpixAddMultConstant`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// In place, each value becomes `multc * value + addc`.
+    pub fn add_mult_constant(&mut self, addc: l_float64, multc: l_float64) {
+        unsafe { dpixAddMultConstant(self.0, addc, multc) };
+    }
+
+    /// Wrapper for [`dpixLinearCombination`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// Returns `a * self + b * other`, aligned to the upper left corner.
+    pub fn linear_combination(
+        &self,
+        other: &Dpix,
+        a: l_float32,
+        b: l_float32,
+    ) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { dpixLinearCombination(std::ptr::null_mut(), self.0, other.0, a, b) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`dpixConvertToPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// Values are rounded to the nearest integer.
+    pub fn convert_to_pix(
+        &self,
+        outdepth: OutDepth,
+        negvals: NegativeValues,
+    ) -> Option<RefCountedExclusive<Pix>> {
+        let ptr =
+            unsafe { dpixConvertToPix(self.0, outdepth.to_leptonica(), negvals.to_leptonica(), 0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for Dpix {
+    unsafe fn destroy(&mut self) {
+        dpixDestroy(&mut self.0);
+    }
+}
+
+impl LeptonicaClone for Dpix {
+    unsafe fn clone(&mut self) -> Self {
+        Self::new_from_pointer(dpixClone(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_test() {
+        let dpix = Dpix::create(7, 3).unwrap();
+        assert_eq!(dpix.get_dimensions(), (7, 3));
+        assert!(dpix.get_wpl() >= 7);
+        assert_eq!(dpix.get_row(2).unwrap(), &[0.0; 7]);
+        assert!(dpix.get_row(3).is_none());
+        assert!(dpix.get_row(-1).is_none());
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let mut dpix_1 = Dpix::create(2, 2).unwrap();
+        dpix_1.get_row_mut(0).unwrap().copy_from_slice(&[1.0, 2.0]);
+        dpix_1.get_row_mut(1).unwrap().copy_from_slice(&[3.0, 4.0]);
+        let mut dpix_2 = Dpix::create(2, 2).unwrap();
+        dpix_2.add_mult_constant(1.0, 2.0);
+        assert_eq!(dpix_2.get_row(1).unwrap(), &[1.0, 1.0]);
+
+        let sum = dpix_1.linear_combination(&dpix_2, 2.0, -1.0).unwrap();
+        assert_eq!(sum.get_row(0).unwrap(), &[1.0, 3.0]);
+        assert_eq!(sum.get_row(1).unwrap(), &[5.0, 7.0]);
+    }
+
+    #[test]
+    fn convert_from_pix_test() {
+        let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let dpix = pix.convert_to_dpix(1).unwrap();
+        assert_eq!(dpix.get_dimensions(), (200, 23));
+        let pix = dpix
+            .convert_to_pix(OutDepth::Bpp8, NegativeValues::ClipToZero)
+            .unwrap();
+        assert_eq!(pix.get_width(), 200);
+        assert_eq!(pix.get_depth(), 8);
+    }
+}
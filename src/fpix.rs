@@ -0,0 +1,272 @@
+use crate::{
+    memory::{LeptonicaClone, LeptonicaDestroy, RefCountedExclusive},
+    Pix,
+};
+
+use leptonica_sys::{
+    fpixAddMultConstant, fpixClone, fpixConvertToPix, fpixCreate, fpixDestroy, fpixGetData,
+    fpixGetDimensions, fpixGetWpl, fpixLinearCombination, l_float32, l_int32, L_CLIP_TO_ZERO,
+    L_TAKE_ABSVAL,
+};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`FPix`](https://tpgit.github.io/Leptonica/struct_f_pix.html) structure
+#[derive(Debug, PartialEq)]
+pub struct Fpix(*mut leptonica_sys::FPix);
+
+/// How negative values are handled when converting floating point images to a `Pix`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegativeValues {
+    /// `L_CLIP_TO_ZERO`
+    ClipToZero,
+    /// `L_TAKE_ABSVAL`
+    TakeAbsoluteValue,
+}
+
+impl NegativeValues {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::ClipToZero => L_CLIP_TO_ZERO,
+            Self::TakeAbsoluteValue => L_TAKE_ABSVAL,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+/// Output depth when converting floating point images to a `Pix`
+///
+/// Values larger than a fixed depth can hold are clipped to its maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutDepth {
+    /// Use the smallest depth of 8, 16 or 32 bpp that holds the largest value
+    Auto,
+    Bpp8,
+    Bpp16,
+    Bpp32,
+}
+
+impl OutDepth {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Auto => 0,
+            Self::Bpp8 => 8,
+            Self::Bpp16 => 16,
+            Self::Bpp32 => 32,
+        }
+    }
+}
+
+impl AsRef<leptonica_sys::FPix> for Fpix {
+    fn as_ref(&self) -> &leptonica_sys::FPix {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::FPix> for Fpix {
+    fn as_mut(&mut self) -> &mut leptonica_sys::FPix {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Fpix {
+    /// Create a new Fpix from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid FPix struct.
+    /// The FPix struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::FPix) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`fpixCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// Input: width, height. The data is initialized to 0.
+    pub fn create(width: l_int32, height: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { fpixCreate(width, height) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`fpixGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// Returns the width and height.
+    pub fn get_dimensions(&self) -> (l_int32, l_int32) {
+        let (mut w, mut h) = (0, 0);
+        unsafe { fpixGetDimensions(self.0, &mut w, &mut h) };
+        (w, h)
+    }
+
+    /// Wrapper for [`fpixGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c) returning only the width
+    pub fn get_width(&self) -> l_int32 {
+        self.get_dimensions().0
+    }
+
+    /// Wrapper for [`fpixGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c) returning only the height
+    pub fn get_height(&self) -> l_int32 {
+        self.get_dimensions().1
+    }
+
+    /// Wrapper for [`fpixGetWpl`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// Number of values per line, including any padding.
+    pub fn get_wpl(&self) -> l_int32 {
+        unsafe { fpixGetWpl(self.0) }
+    }
+
+    /// Wrapper for [`fpixGetData`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// All rows, each of length `get_wpl()`.
+    pub fn get_data(&self) -> &[l_float32] {
+        let len = (self.get_wpl() * self.get_height()).try_into().unwrap();
+        unsafe { std::slice::from_raw_parts(fpixGetData(self.0), len) }
+    }
+
+    /// Wrapper for [`fpixGetData`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    ///
+    /// All rows, each of length `get_wpl()`.
+    pub fn get_data_mut(&mut self) -> &mut [l_float32] {
+        let len = (self.get_wpl() * self.get_height()).try_into().unwrap();
+        unsafe { std::slice::from_raw_parts_mut(fpixGetData(self.0), len) }
+    }
+
+    /// The values of row `y`, or `None` if out of bounds
+    pub fn get_row(&self, y: l_int32) -> Option<&[l_float32]> {
+        let (start, end) = self.row_range(y)?;
+        Some(&self.get_data()[start..end])
+    }
+
+    /// The values of row `y`, or `None` if out of bounds
+    pub fn get_row_mut(&mut self, y: l_int32) -> Option<&mut [l_float32]> {
+        let (start, end) = self.row_range(y)?;
+        Some(&mut self.get_data_mut()[start..end])
+    }
+
+    fn row_range(&self, y: l_int32) -> Option<(usize, usize)> {
+        if y < 0 || y >= self.get_height() {
+            return None;
+        }
+        let start: usize = (y * self.get_wpl()).try_into().unwrap();
+        let width: usize = self.get_width().try_into().unwrap();
+        Some((start, start + width))
+    }
+
+    /// Wrapper for [`fpixAddMultConstant`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// In place, each value becomes `multc * value + addc`.
+    pub fn add_mult_constant(&mut self, addc: l_float32, multc: l_float32) {
+        unsafe { fpixAddMultConstant(self.0, addc, multc) };
+    }
+
+    /// Wrapper for [`fpixLinearCombination`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// Returns `a * self + b * other`, aligned to the upper left corner.
+    pub fn linear_combination(
+        &self,
+        other: &Fpix,
+        a: l_float32,
+        b: l_float32,
+    ) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { fpixLinearCombination(std::ptr::null_mut(), self.0, other.0, a, b) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`fpixConvertToPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// Values are rounded to the nearest integer.
+    pub fn convert_to_pix(
+        &self,
+        outdepth: OutDepth,
+        negvals: NegativeValues,
+    ) -> Option<RefCountedExclusive<Pix>> {
+        let ptr =
+            unsafe { fpixConvertToPix(self.0, outdepth.to_leptonica(), negvals.to_leptonica(), 0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for Fpix {
+    unsafe fn destroy(&mut self) {
+        fpixDestroy(&mut self.0);
+    }
+}
+
+impl LeptonicaClone for Fpix {
+    unsafe fn clone(&mut self) -> Self {
+        Self::new_from_pointer(fpixClone(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptonica_sys::pixGetPixel;
+
+    #[test]
+    fn create_test() {
+        let fpix = Fpix::create(7, 3).unwrap();
+        assert_eq!(fpix.get_dimensions(), (7, 3));
+        assert!(fpix.get_wpl() >= 7);
+        assert_eq!(fpix.get_row(2).unwrap(), &[0.0; 7]);
+        assert!(fpix.get_row(3).is_none());
+        assert!(fpix.get_row(-1).is_none());
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let mut fpix_1 = Fpix::create(2, 2).unwrap();
+        fpix_1.get_row_mut(0).unwrap().copy_from_slice(&[1.0, 2.0]);
+        fpix_1.get_row_mut(1).unwrap().copy_from_slice(&[3.0, 4.0]);
+        let mut fpix_2 = Fpix::create(2, 2).unwrap();
+        fpix_2.add_mult_constant(1.0, 2.0);
+        assert_eq!(fpix_2.get_row(1).unwrap(), &[1.0, 1.0]);
+
+        let sum = fpix_1.linear_combination(&fpix_2, 2.0, -1.0).unwrap();
+        assert_eq!(sum.get_row(0).unwrap(), &[1.0, 3.0]);
+        assert_eq!(sum.get_row(1).unwrap(), &[5.0, 7.0]);
+    }
+
+    #[test]
+    fn convert_to_pix_test() {
+        let mut fpix = Fpix::create(2, 1).unwrap();
+        fpix.get_row_mut(0).unwrap().copy_from_slice(&[-5.0, 300.0]);
+        let mut val = 0;
+
+        let pix = fpix
+            .convert_to_pix(OutDepth::Bpp8, NegativeValues::ClipToZero)
+            .unwrap();
+        assert_eq!(pix.get_depth(), 8);
+        unsafe { pixGetPixel(*pix.as_ref(), 0, 0, &mut val) };
+        assert_eq!(val, 0);
+        unsafe { pixGetPixel(*pix.as_ref(), 1, 0, &mut val) };
+        assert_eq!(val, 255);
+
+        let pix = fpix
+            .convert_to_pix(OutDepth::Auto, NegativeValues::TakeAbsoluteValue)
+            .unwrap();
+        assert_eq!(pix.get_depth(), 16);
+        unsafe { pixGetPixel(*pix.as_ref(), 0, 0, &mut val) };
+        assert_eq!(val, 5);
+        unsafe { pixGetPixel(*pix.as_ref(), 1, 0, &mut val) };
+        assert_eq!(val, 300);
+    }
+
+    #[test]
+    fn convert_from_pix_test() {
+        let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let fpix = pix.convert_to_fpix(1).unwrap();
+        assert_eq!(fpix.get_dimensions(), (200, 23));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCounted, RefCountedExclusive},
+    Fpix,
+};
+use leptonica_sys::{fpixaDestroy, fpixaGetCount, fpixaGetFPix, l_int32, L_CLONE, L_COPY};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`FPixa`](https://tpgit.github.io/Leptonica/struct_f_pixa.html) structure
+#[derive(Debug, PartialEq)]
+pub struct Fpixa(*mut leptonica_sys::FPixa);
+
+impl AsRef<*mut leptonica_sys::FPixa> for Fpixa {
+    fn as_ref(&self) -> &*mut leptonica_sys::FPixa {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::FPixa> for Fpixa {
+    fn as_ref(&self) -> &leptonica_sys::FPixa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::FPixa> for Fpixa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::FPixa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Fpixa {
+    /// Create a new Fpixa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid FPixa struct.
+    /// The FPixa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::FPixa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`fpixaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { fpixaGetCount(self.0) }
+    }
+
+    /// Wrapper for [`fpixaGetFPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c) with copied `accesstype`: `L_COPY`
+    pub fn get_fpix_copied(&self, index: l_int32) -> Option<RefCountedExclusive<Fpix>> {
+        unsafe {
+            fpixaGetFPix(self.0, index, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Fpix::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`fpixaGetFPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix1.c) with cloned `accesstype`: `L_CLONE`
+    pub fn get_fpix_cloned(&self, index: l_int32) -> Option<RefCounted<Fpix>> {
+        unsafe {
+            fpixaGetFPix(self.0, index, L_CLONE.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCounted::new(Fpix::new_from_pointer(raw)))
+        }
+    }
+}
+
+impl LeptonicaDestroy for Fpixa {
+    unsafe fn destroy(&mut self) {
+        fpixaDestroy(&mut self.0);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    memory::{BorrowedFrom, LeptonicaDestroy, RefCountedExclusive},
+    Numa, Pix, Pixa,
+};
+
+use leptonica_sys::{
+    jbAddPage, jbClasserDestroy, jbCorrelationInit, jbDataDestroy, jbDataRender, jbDataSave,
+    jbRankHausInit, l_float32, l_int32, JB_CHARACTERS, JB_CONN_COMPS, JB_WORDS,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Wrapper around Leptonica's [`JbClasser`](https://tpgit.github.io/Leptonica/struct_jb_classer.html) structure
+///
+/// Clusters the components of 1 bpp pages into classes of similar shapes, as used for
+/// JBIG2 symbol compression.
+#[derive(Debug, PartialEq)]
+pub struct JbClassifier(*mut leptonica_sys::JbClasser);
+
+/// Error returned by JbClassifier::create_rank_haus and JbClassifier::create_correlation
+#[derive(Debug, Error)]
+#[error("JbClassifier::create returned null")]
+pub struct JbClassifierCreateError();
+
+/// Error returned by the JbClassifier page adding and rendering methods
+#[derive(Debug, Error, PartialEq)]
+pub enum JbClassifierError {
+    #[error("Pix depth {0} is not supported, expected 1")]
+    UnsupportedDepth(l_int32),
+    #[error("Failed to add the page")]
+    AddPage,
+    #[error("Failed to save the classification data")]
+    Save,
+    #[error("Failed to render the pages")]
+    Render,
+}
+
+/// The components of a page that are classified
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JbComponents {
+    /// `JB_CONN_COMPS`: the 8-connected components
+    ConnComps,
+    /// `JB_CHARACTERS`: the characters, joining the components of each
+    Characters,
+    /// `JB_WORDS`: the words
+    Words,
+}
+
+impl JbComponents {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::ConnComps => JB_CONN_COMPS,
+            Self::Characters => JB_CHARACTERS,
+            Self::Words => JB_WORDS,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl AsRef<leptonica_sys::JbClasser> for JbClassifier {
+    fn as_ref(&self) -> &leptonica_sys::JbClasser {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::JbClasser> for JbClassifier {
+    fn as_mut(&mut self) -> &mut leptonica_sys::JbClasser {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl JbClassifier {
+    /// Create a new JbClassifier from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid JbClasser struct.
+    /// The JbClasser struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::JbClasser) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`jbRankHausInit`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/jbclass.c)
+    ///
+    /// Input: the largest component kept (maxwidth, maxheight), size of the Hausdorff
+    /// structuring element (1 to 10, typically 2) and rank (0.5 to 1.0, typically 0.97).
+    pub fn create_rank_haus(
+        components: JbComponents,
+        maxwidth: l_int32,
+        maxheight: l_int32,
+        size: l_int32,
+        rank: l_float32,
+    ) -> Result<RefCountedExclusive<Self>, JbClassifierCreateError> {
+        Self::from_init(unsafe {
+            jbRankHausInit(components.to_leptonica(), maxwidth, maxheight, size, rank)
+        })
+    }
+
+    /// Wrapper for [`jbCorrelationInit`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/jbclass.c)
+    ///
+    /// Input: the largest component kept (maxwidth, maxheight), correlation threshold
+    /// (0.4 to 0.98, typically 0.8) and weightfactor (0.0 to 1.0, typically 0.6), which raises
+    /// the threshold for heavier components.
+    pub fn create_correlation(
+        components: JbComponents,
+        maxwidth: l_int32,
+        maxheight: l_int32,
+        thresh: l_float32,
+        weightfactor: l_float32,
+    ) -> Result<RefCountedExclusive<Self>, JbClassifierCreateError> {
+        Self::from_init(unsafe {
+            jbCorrelationInit(
+                components.to_leptonica(),
+                maxwidth,
+                maxheight,
+                thresh,
+                weightfactor,
+            )
+        })
+    }
+
+    /// Wrapper for [`jbAddPage`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/jbclass.c)
+    ///
+    /// Input: 1 bpp page. Classifies its components, adding new templates as needed.
+    pub fn add_page(&mut self, pix: &Pix) -> Result<(), JbClassifierError> {
+        pix.check_depth(&[1], JbClassifierError::UnsupportedDepth)?;
+        if unsafe { jbAddPage(self.0, *pix.as_ref()) } != 0 {
+            Err(JbClassifierError::AddPage)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// In memory variant of [`jbAddPages`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/jbclass.c)
+    ///
+    /// Adds each page of the Pixa in turn, see [`JbClassifier::add_page`].
+    pub fn add_pages(&mut self, pixa: &Pixa) -> Result<(), JbClassifierError> {
+        for index in 0..pixa.get_count() {
+            let pix = pixa
+                .get_pix_cloned(index)
+                .ok_or(JbClassifierError::AddPage)?;
+            self.add_page(&pix)?;
+        }
+        Ok(())
+    }
+
+    /// Number of pages added
+    pub fn get_page_count(&self) -> l_int32 {
+        AsRef::<leptonica_sys::JbClasser>::as_ref(self).npages
+    }
+
+    /// Number of classes found
+    pub fn get_class_count(&self) -> l_int32 {
+        AsRef::<leptonica_sys::JbClasser>::as_ref(self).nclass
+    }
+
+    /// Get the template of each class, with a border added around it
+    pub fn get_templates(&self) -> Option<BorrowedFrom<'_, Pixa>> {
+        let ptr = AsRef::<leptonica_sys::JbClasser>::as_ref(self).pixat;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(Pixa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Get the class of each component, in the order of the pages they were found in
+    pub fn get_class_ids(&self) -> Option<BorrowedFrom<'_, Numa>> {
+        Self::borrow_numa(AsRef::<leptonica_sys::JbClasser>::as_ref(self).naclass)
+    }
+
+    /// Get the page of each component
+    pub fn get_page_ids(&self) -> Option<BorrowedFrom<'_, Numa>> {
+        Self::borrow_numa(AsRef::<leptonica_sys::JbClasser>::as_ref(self).napage)
+    }
+
+    /// Wrapper for [`jbDataSave`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/jbclass.c)
+    /// and [`jbDataRender`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/jbclass.c)
+    ///
+    /// Reconstructs each page from the class templates, for verification.
+    pub fn render(&self) -> Result<RefCountedExclusive<Pixa>, JbClassifierError> {
+        let mut data = unsafe { jbDataSave(self.0) };
+        if data.is_null() {
+            return Err(JbClassifierError::Save);
+        }
+        let ptr = unsafe { jbDataRender(data, 0) };
+        unsafe { jbDataDestroy(&mut data) };
+        if ptr.is_null() {
+            Err(JbClassifierError::Render)
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Pixa::new_from_pointer(ptr)) })
+        }
+    }
+
+    fn from_init(
+        ptr: *mut leptonica_sys::JbClasser,
+    ) -> Result<RefCountedExclusive<Self>, JbClassifierCreateError> {
+        if ptr.is_null() {
+            Err(JbClassifierCreateError())
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    fn borrow_numa<'a>(ptr: *mut leptonica_sys::Numa) -> Option<BorrowedFrom<'a, Numa>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(Numa::new_from_pointer(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for JbClassifier {
+    unsafe fn destroy(&mut self) {
+        jbClasserDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::binary_text_image;
+
+    #[test]
+    fn correlation_test() {
+        let pix = binary_text_image();
+        let mut classifier =
+            JbClassifier::create_correlation(JbComponents::ConnComps, 150, 150, 0.8, 0.6).unwrap();
+        classifier.add_page(&pix).unwrap();
+        assert_eq!(classifier.get_page_count(), 1);
+
+        let nclass = classifier.get_class_count();
+        assert!(nclass > 0);
+        assert_eq!(classifier.get_templates().unwrap().get_count(), nclass);
+        let class_ids = classifier.get_class_ids().unwrap();
+        assert!(class_ids.get_count() >= nclass);
+        assert_eq!(
+            classifier.get_page_ids().unwrap().get_count(),
+            class_ids.get_count()
+        );
+
+        let pages = classifier.render().unwrap();
+        assert_eq!(pages.get_count(), 1);
+        let page = pages.get_pix_cloned(0).unwrap();
+        assert_eq!(page.get_width(), pix.get_width());
+        assert_eq!(page.get_height(), pix.get_height());
+    }
+
+    #[test]
+    fn rank_haus_test() {
+        let mut classifier =
+            JbClassifier::create_rank_haus(JbComponents::ConnComps, 150, 150, 2, 0.97).unwrap();
+        let color = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        assert_eq!(
+            classifier.add_page(&color),
+            Err(JbClassifierError::UnsupportedDepth(32))
+        );
+        classifier.add_page(&binary_text_image()).unwrap();
+        assert!(classifier.get_class_count() > 0);
+    }
+}
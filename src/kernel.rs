@@ -0,0 +1,186 @@
+use crate::memory::{LeptonicaDestroy, RefCountedExclusive};
+
+use leptonica_sys::{
+    kernelCreate, kernelCreateFromString, kernelDestroy, kernelGetElement, kernelGetParameters,
+    kernelGetSum, kernelNormalize, kernelSetElement, kernelSetOrigin, l_float32, l_int32, l_ok,
+    makeGaussianKernel, makeGaussianKernelSep,
+};
+use std::ffi::CStr;
+
+/// Wrapper around Leptonica's [`L_Kernel`](https://tpgit.github.io/Leptonica/struct_l___kernel.html) structure
+#[derive(Debug, PartialEq)]
+pub struct Kernel(*mut leptonica_sys::L_Kernel);
+
+impl AsRef<*mut leptonica_sys::L_Kernel> for Kernel {
+    fn as_ref(&self) -> &*mut leptonica_sys::L_Kernel {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::L_Kernel> for Kernel {
+    fn as_ref(&self) -> &leptonica_sys::L_Kernel {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::L_Kernel> for Kernel {
+    fn as_mut(&mut self) -> &mut leptonica_sys::L_Kernel {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Kernel {
+    /// Create a new Kernel from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid L_Kernel struct.
+    /// The L_Kernel struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::L_Kernel) -> Self {
+        Self(p)
+    }
+
+    fn from_ptr(ptr: *mut leptonica_sys::L_Kernel) -> Option<RefCountedExclusive<Self>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`kernelCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    ///
+    /// Input: height, width. The elements are initialized to 0 and the origin to (0, 0).
+    pub fn create(height: l_int32, width: l_int32) -> Option<RefCountedExclusive<Self>> {
+        Self::from_ptr(unsafe { kernelCreate(height, width) })
+    }
+
+    /// Wrapper for [`kernelCreateFromString`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    ///
+    /// Input: h, w, cy, cx (origin), kdata (`h * w` whitespace separated values, row by row)
+    pub fn create_from_string(
+        h: l_int32,
+        w: l_int32,
+        cy: l_int32,
+        cx: l_int32,
+        kdata: &CStr,
+    ) -> Option<RefCountedExclusive<Self>> {
+        Self::from_ptr(unsafe { kernelCreateFromString(h, w, cy, cx, kdata.as_ptr()) })
+    }
+
+    /// Wrapper for [`makeGaussianKernel`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    ///
+    /// The kernel is `2 * halfh + 1` by `2 * halfw + 1`, with `max` at the origin in the center.
+    pub fn make_gaussian(
+        halfh: l_int32,
+        halfw: l_int32,
+        stdev: l_float32,
+        max: l_float32,
+    ) -> Option<RefCountedExclusive<Self>> {
+        Self::from_ptr(unsafe { makeGaussianKernel(halfh, halfw, stdev, max) })
+    }
+
+    /// Wrapper for [`makeGaussianKernelSep`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    ///
+    /// Returns the horizontal and vertical 1 dimensional kernels.
+    pub fn make_gaussian_sep(
+        halfh: l_int32,
+        halfw: l_int32,
+        stdev: l_float32,
+        max: l_float32,
+    ) -> Option<(RefCountedExclusive<Self>, RefCountedExclusive<Self>)> {
+        let mut kelx = std::ptr::null_mut();
+        let mut kely = std::ptr::null_mut();
+        unsafe { makeGaussianKernelSep(halfh, halfw, stdev, max, &mut kelx, &mut kely) };
+        // Wrap both before checking them, so neither leaks if the other is null
+        let (kelx, kely) = (Self::from_ptr(kelx), Self::from_ptr(kely));
+        Some((kelx?, kely?))
+    }
+
+    /// Wrapper for [`kernelNormalize`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    ///
+    /// Returns a copy whose elements sum to `normsum`.
+    pub fn normalize(&self, normsum: l_float32) -> Option<RefCountedExclusive<Self>> {
+        Self::from_ptr(unsafe { kernelNormalize(self.0, normsum) })
+    }
+
+    /// Wrapper for [`kernelGetElement`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    pub fn get_element(&self, row: l_int32, col: l_int32) -> Option<l_float32> {
+        let mut val = 0.0;
+        if unsafe { kernelGetElement(self.0, row, col, &mut val) } != 0 {
+            None
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Wrapper for [`kernelSetElement`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    pub fn set_element(&mut self, row: l_int32, col: l_int32, val: l_float32) -> l_ok {
+        unsafe { kernelSetElement(self.0, row, col, val) }
+    }
+
+    /// Wrapper for [`kernelGetParameters`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    ///
+    /// Returns the height, width and origin (cy, cx).
+    pub fn get_parameters(&self) -> (l_int32, l_int32, l_int32, l_int32) {
+        let (mut sy, mut sx, mut cy, mut cx) = (0, 0, 0, 0);
+        unsafe { kernelGetParameters(self.0, &mut sy, &mut sx, &mut cy, &mut cx) };
+        (sy, sx, cy, cx)
+    }
+
+    /// Wrapper for [`kernelSetOrigin`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    pub fn set_origin(&mut self, cy: l_int32, cx: l_int32) -> l_ok {
+        unsafe { kernelSetOrigin(self.0, cy, cx) }
+    }
+
+    /// Wrapper for [`kernelGetSum`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/kernel.c)
+    pub fn get_sum(&self) -> l_float32 {
+        let mut sum = 0.0;
+        unsafe { kernelGetSum(self.0, &mut sum) };
+        sum
+    }
+}
+
+impl LeptonicaDestroy for Kernel {
+    unsafe fn destroy(&mut self) {
+        kernelDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_test() {
+        let mut kel = Kernel::create(3, 5).unwrap();
+        assert_eq!(kel.get_parameters(), (3, 5, 0, 0));
+        kel.set_origin(1, 2);
+        kel.set_element(1, 2, 4.0);
+        assert_eq!(kel.get_parameters(), (3, 5, 1, 2));
+        assert_eq!(kel.get_element(1, 2), Some(4.0));
+        assert_eq!(kel.get_element(3, 0), None);
+    }
+
+    #[test]
+    fn create_from_string_test() {
+        let kdata = CStr::from_bytes_with_nul(b" 1 2 1\n 2 4 2\n 1 2 1\0").unwrap();
+        let kel = Kernel::create_from_string(3, 3, 1, 1, kdata).unwrap();
+        assert_eq!(kel.get_element(1, 1), Some(4.0));
+        assert_eq!(kel.get_sum(), 16.0);
+        let kel = kel.normalize(1.0).unwrap();
+        assert_eq!(kel.get_element(1, 1), Some(0.25));
+        assert!((kel.get_sum() - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn make_gaussian_test() {
+        let kel = Kernel::make_gaussian(2, 3, 1.5, 1.0).unwrap();
+        assert_eq!(kel.get_parameters(), (5, 7, 2, 3));
+        assert_eq!(kel.get_element(2, 3), Some(1.0));
+
+        let (kelx, kely) = Kernel::make_gaussian_sep(2, 3, 1.5, 1.0).unwrap();
+        assert_eq!(kelx.get_parameters(), (1, 7, 0, 3));
+        assert_eq!(kely.get_parameters(), (5, 1, 2, 0));
+    }
+}
@@ -1,18 +1,70 @@
+mod bmf;
 mod r#box;
 mod boxa;
+mod boxaa;
+mod ccbord;
+mod ccborda;
+mod colormap;
+pub mod compare;
+mod dewarp;
+mod dewarpa;
+mod dpix;
+mod fpix;
+mod fpixa;
+mod jbclassifier;
+mod kernel;
 pub mod memory;
+pub mod message;
+mod numa;
+mod numaa;
 mod pix;
 mod pixa;
+mod pixaa;
+mod pixacomp;
+mod pixcomp;
+mod pta;
+mod ptaa;
+mod recognizer;
 mod str;
+#[cfg(test)]
+mod test_fixtures;
 
 use self::leptonica_sys::{getImagelibVersions, getLeptonicaVersion};
 pub use leptonica_sys;
 
 pub use crate::str::Str;
+pub use bmf::Bmf;
 pub use boxa::Boxa;
-pub use pix::{Pix, PixReadError, PixReadMemError};
-pub use pixa::Pixa;
+pub use boxaa::Boxaa;
+pub use ccbord::CcBord;
+pub use ccborda::{CcBorda, CcBordaCreateError, CcBordaGenerateError};
+pub use colormap::PixColormap;
+pub use dewarp::{Dewarp, DewarpCreateError};
+pub use dewarpa::{Dewarpa, DewarpaCreateError, DewarpaError};
+pub use dpix::Dpix;
+pub use fpix::{Fpix, NegativeValues, OutDepth};
+pub use fpixa::Fpixa;
+pub use jbclassifier::{JbClassifier, JbClassifierCreateError, JbClassifierError, JbComponents};
+pub use kernel::Kernel;
+pub use numa::Numa;
+pub use numaa::Numaa;
+pub use pix::{
+    ColorComponent, Connectivity, DistanceBoundary, EdgeOrientation, LineOrientation, LineStat,
+    MinOrMax, Orientation, OrientationEstimate, Pix, PixArithError, PixClipError,
+    PixColorspaceError, PixConnCompError, PixConvolveError, PixEdgeError, PixFilterError,
+    PixGrayMorphError, PixLinesError, PixOrientError, PixQuantizeError, PixReadError,
+    PixReadMemError, PixRenderError, PixSeedfillError, PixStatsError, PixTextError,
+    PixTextlineError, Quantized, RangeRegion, RasterOp, RenderOp, Rgb, StatType, TextLocation,
+    Tophat,
+};
+pub use pixa::{Pixa, TileBackground};
+pub use pixaa::Pixaa;
+pub use pixacomp::PixaComp;
+pub use pixcomp::{CompressionFormat, PixComp};
+pub use pta::Pta;
+pub use ptaa::Ptaa;
 pub use r#box::{Box, BoxCreateValidError};
+pub use recognizer::{Identification, Recognizer, RecognizerCreateError, RecognizerError};
 
 /// Wrapper for [`getLeptonicaVersion`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/utils1.c#L970-L982)
 ///
@@ -4,15 +4,16 @@ use std::ops::{Deref, DerefMut};
 /// A wrapper for ref counted leptonica pointers that can be safely mutated.
 ///
 /// For example if it is the only reference.
+#[derive(Debug)]
 pub struct RefCountedExclusive<T: LeptonicaDestroy> {
     inner: T,
 }
 
 impl<T: LeptonicaDestroy> RefCountedExclusive<T> {
     /// Creates a new ref counted exclusive wrapper
-
+    ///
     /// # Safety
-
+    ///
     /// It must be safe for this wrapper to destroy (decrement the ref count).
     /// The ref count must have already been incremented before being passed to `new`.
     /// The pointer must not be mutated whilst this wrapper exists, except via this wrapper.
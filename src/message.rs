@@ -0,0 +1,187 @@
+//! Routing of Leptonica's error, warning and info messages
+//!
+//! Leptonica writes its messages to stderr by default. [`route_to_log`] sends them to the
+//! [`log`] crate instead, under the `leptonica` target. Once routed, [`capture`] also collects
+//! the messages written on the current thread, so they can be attached to returned errors
+//! such as [`crate::PixReadError`].
+
+use leptonica_sys::{
+    l_int32, leptSetStderrHandler, setMsgSeverity, L_SEVERITY_ALL, L_SEVERITY_DEBUG,
+    L_SEVERITY_ERROR, L_SEVERITY_EXTERNAL, L_SEVERITY_INFO, L_SEVERITY_NONE, L_SEVERITY_WARNING,
+};
+use libc::c_char;
+use std::{
+    cell::RefCell,
+    convert::TryInto,
+    ffi::CStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Minimum severity of the messages Leptonica writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// `L_SEVERITY_EXTERNAL`: read from the `LEPT_MSG_SEVERITY` environment variable
+    External,
+    /// `L_SEVERITY_ALL`
+    All,
+    /// `L_SEVERITY_DEBUG`
+    Debug,
+    /// `L_SEVERITY_INFO`: the default
+    Info,
+    /// `L_SEVERITY_WARNING`
+    Warning,
+    /// `L_SEVERITY_ERROR`
+    Error,
+    /// `L_SEVERITY_NONE`: no messages
+    None,
+}
+
+impl Severity {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::External => L_SEVERITY_EXTERNAL,
+            Self::All => L_SEVERITY_ALL,
+            Self::Debug => L_SEVERITY_DEBUG,
+            Self::Info => L_SEVERITY_INFO,
+            Self::Warning => L_SEVERITY_WARNING,
+            Self::Error => L_SEVERITY_ERROR,
+            Self::None => L_SEVERITY_NONE,
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    fn from_leptonica(severity: l_int32) -> Self {
+        match severity.try_into() {
+            Ok(L_SEVERITY_ALL) => Self::All,
+            Ok(L_SEVERITY_DEBUG) => Self::Debug,
+            Ok(L_SEVERITY_INFO) => Self::Info,
+            Ok(L_SEVERITY_WARNING) => Self::Warning,
+            Ok(L_SEVERITY_ERROR) => Self::Error,
+            Ok(L_SEVERITY_NONE) => Self::None,
+            _ => Self::External,
+        }
+    }
+}
+
+/// Whether [`route_to_log`] installed the handler
+static ROUTED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Messages written on this thread during [`capture`], if one is running
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Wrapper for [`setMsgSeverity`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/utils1.c)
+///
+/// Sets the minimum severity of the messages written, for all threads.
+/// Returns the previous severity.
+pub fn set_severity(severity: Severity) -> Severity {
+    Severity::from_leptonica(unsafe { setMsgSeverity(severity.to_leptonica()) })
+}
+
+/// Wrapper for [`leptSetStderrHandler`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/utils1.c)
+///
+/// Sends Leptonica's messages to the [`log`] crate, for all threads. Errors, warnings and info
+/// are logged at the matching level, anything else at debug level.
+/// Nothing in this crate calls it, so the handler is only replaced when the application asks.
+pub fn route_to_log() {
+    unsafe { leptSetStderrHandler(Some(handle_message)) }
+    ROUTED.store(true, Ordering::SeqCst);
+}
+
+/// Runs `f`, collecting the Leptonica messages written on this thread meanwhile
+///
+/// Messages are only collected once they are routed with [`route_to_log`];
+/// otherwise Leptonica keeps writing them to stderr and none are returned.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    if !ROUTED.load(Ordering::SeqCst) {
+        return (f(), Vec::new());
+    }
+    let guard = CaptureGuard {
+        outer: CAPTURED.with(|captured| captured.replace(Some(Vec::new()))),
+    };
+    let result = f();
+    (result, guard.finish())
+}
+
+/// Restores the enclosing capture of this thread, even if the captured function panics
+struct CaptureGuard {
+    outer: Option<Vec<String>>,
+}
+
+impl CaptureGuard {
+    /// Ends the capture, returning the messages collected
+    fn finish(self) -> Vec<String> {
+        CAPTURED
+            .with(|captured| captured.borrow_mut().take())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for CaptureGuard {
+    fn drop(&mut self) {
+        let outer = self.outer.take();
+        let _ = CAPTURED.try_with(|captured| captured.replace(outer));
+    }
+}
+
+/// Formats captured messages as a suffix of an error message
+pub(crate) fn describe(messages: &[String]) -> String {
+    if messages.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", messages.join("; "))
+    }
+}
+
+unsafe extern "C" fn handle_message(msg: *const c_char) {
+    if msg.is_null() {
+        return;
+    }
+    let msg = CStr::from_ptr(msg).to_string_lossy();
+    let msg = msg.trim_end();
+    if msg.starts_with("Error") {
+        log::error!(target: "leptonica", "{}", msg);
+    } else if msg.starts_with("Warning") {
+        log::warn!(target: "leptonica", "{}", msg);
+    } else if msg.starts_with("Info") {
+        log::info!(target: "leptonica", "{}", msg);
+    } else {
+        log::debug!(target: "leptonica", "{}", msg);
+    }
+    // Never panic across the FFI boundary, even while the thread is shutting down
+    let _ = CAPTURED.try_with(|captured| {
+        if let Ok(mut captured) = captured.try_borrow_mut() {
+            if let Some(messages) = captured.as_mut() {
+                messages.push(msg.to_string());
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests of routed messages are in tests/message.rs, as routing replaces the handler of
+    // the whole process
+
+    #[test]
+    fn capture_unrouted_test() {
+        let (result, messages) =
+            capture(|| unsafe { leptonica_sys::pixRead(b"fail\0".as_ptr().cast()).is_null() });
+        assert!(result);
+        assert!(messages.is_empty());
+        assert!(CAPTURED.with(|captured| captured.borrow().is_none()));
+    }
+
+    #[test]
+    fn describe_test() {
+        assert_eq!(describe(&[]), "");
+        assert_eq!(
+            describe(&["Error in a".to_string(), "Error in b".to_string()]),
+            ": Error in a; Error in b"
+        );
+    }
+}
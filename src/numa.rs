@@ -0,0 +1,118 @@
+use crate::memory::{LeptonicaClone, LeptonicaDestroy, RefCountedExclusive};
+
+use leptonica_sys::{
+    l_float32, l_int32, l_ok, numaAddNumber, numaClone, numaCreate, numaDestroy, numaGetCount,
+    numaGetFValue, numaGetIValue,
+};
+
+/// Wrapper around Leptonica's [`Numa`](https://tpgit.github.io/Leptonica/struct_numa.html) structure
+///
+/// An array of numbers.
+#[derive(Debug, PartialEq)]
+pub struct Numa(*mut leptonica_sys::Numa);
+
+impl AsRef<*mut leptonica_sys::Numa> for Numa {
+    fn as_ref(&self) -> &*mut leptonica_sys::Numa {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::Numa> for Numa {
+    fn as_ref(&self) -> &leptonica_sys::Numa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::Numa> for Numa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::Numa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Numa {
+    /// Create a new Numa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid Numa struct.
+    /// The Numa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::Numa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`numaCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c)
+    ///
+    /// Input: n (initial array size)
+    pub fn create(n: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { numaCreate(n) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`numaAddNumber`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c)
+    pub fn add_number(&mut self, val: l_float32) -> l_ok {
+        unsafe { numaAddNumber(self.0, val) }
+    }
+
+    /// Wrapper for [`numaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { numaGetCount(self.0) }
+    }
+
+    /// Wrapper for [`numaGetFValue`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c)
+    pub fn get_fvalue(&self, index: l_int32) -> Option<l_float32> {
+        let mut val = 0.0;
+        if unsafe { numaGetFValue(self.0, index, &mut val) } != 0 {
+            None
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Wrapper for [`numaGetIValue`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c)
+    ///
+    /// The value rounded to an integer.
+    pub fn get_ivalue(&self, index: l_int32) -> Option<l_int32> {
+        let mut val = 0;
+        if unsafe { numaGetIValue(self.0, index, &mut val) } != 0 {
+            None
+        } else {
+            Some(val)
+        }
+    }
+
+    /// All values, in order
+    pub fn to_vec(&self) -> Vec<l_float32> {
+        (0..self.get_count())
+            .filter_map(|i| self.get_fvalue(i))
+            .collect()
+    }
+}
+
+impl LeptonicaDestroy for Numa {
+    unsafe fn destroy(&mut self) {
+        numaDestroy(&mut self.0);
+    }
+}
+
+impl LeptonicaClone for Numa {
+    unsafe fn clone(&mut self) -> Self {
+        Self::new_from_pointer(numaClone(self.0))
+    }
+}
+
+#[test]
+fn add_get_test() {
+    let mut numa = Numa::create(2).unwrap();
+    assert_eq!(numa.get_count(), 0);
+    numa.add_number(1.5);
+    numa.add_number(-3.0);
+    assert_eq!(numa.get_count(), 2);
+    assert_eq!(numa.get_fvalue(0), Some(1.5));
+    assert_eq!(numa.get_ivalue(1), Some(-3));
+    assert_eq!(numa.get_fvalue(2), None);
+    assert_eq!(numa.to_vec(), vec![1.5, -3.0]);
+}
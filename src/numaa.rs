@@ -0,0 +1,72 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCounted, RefCountedExclusive},
+    Numa,
+};
+
+use leptonica_sys::{l_int32, numaaDestroy, numaaGetCount, numaaGetNuma, L_CLONE, L_COPY};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`Numaa`](https://tpgit.github.io/Leptonica/struct_numaa.html) structure
+///
+/// An array of number arrays.
+#[derive(Debug, PartialEq)]
+pub struct Numaa(*mut leptonica_sys::Numaa);
+
+impl AsRef<*mut leptonica_sys::Numaa> for Numaa {
+    fn as_ref(&self) -> &*mut leptonica_sys::Numaa {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::Numaa> for Numaa {
+    fn as_ref(&self) -> &leptonica_sys::Numaa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::Numaa> for Numaa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::Numaa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Numaa {
+    /// Create a new Numaa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid Numaa struct.
+    /// The Numaa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::Numaa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`numaaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { numaaGetCount(self.0) }
+    }
+
+    /// Wrapper for [`numaaGetNuma`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c) with copied `accessflag`: `L_COPY`
+    pub fn get_numa_copied(&self, index: l_int32) -> Option<RefCountedExclusive<Numa>> {
+        unsafe {
+            numaaGetNuma(self.0, index, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Numa::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`numaaGetNuma`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/numabasic.c) with cloned `accessflag`: `L_CLONE`
+    pub fn get_numa_cloned(&self, index: l_int32) -> Option<RefCounted<Numa>> {
+        unsafe {
+            numaaGetNuma(self.0, index, L_CLONE.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCounted::new(Numa::new_from_pointer(raw)))
+        }
+    }
+}
+
+impl LeptonicaDestroy for Numaa {
+    unsafe fn destroy(&mut self) {
+        numaaDestroy(&mut self.0);
+    }
+}
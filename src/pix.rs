@@ -1,9 +1,46 @@
+mod arith;
+mod clip;
+mod colorspace;
+mod conncomp;
+mod convolve;
+mod edge;
+mod filter;
+mod gray_morph;
+mod lines;
+mod orient;
+mod quantize;
+mod render;
+mod seedfill;
+mod stats;
+mod text;
+mod textline;
+
+pub use self::arith::{MinOrMax, PixArithError};
+pub use self::clip::{PixClipError, RasterOp};
+pub use self::colorspace::{ColorComponent, PixColorspaceError, RangeRegion};
+pub use self::conncomp::{Connectivity, PixConnCompError};
+pub use self::convolve::PixConvolveError;
+pub use self::edge::{EdgeOrientation, PixEdgeError};
+pub use self::filter::PixFilterError;
+pub use self::gray_morph::{PixGrayMorphError, Tophat};
+pub use self::lines::{LineOrientation, PixLinesError};
+pub use self::orient::{Orientation, OrientationEstimate, PixOrientError};
+pub use self::quantize::{PixQuantizeError, Quantized};
+pub use self::render::{PixRenderError, RenderOp, Rgb};
+pub use self::seedfill::{DistanceBoundary, PixSeedfillError};
+pub use self::stats::{LineStat, PixStatsError, StatType};
+pub use self::text::{PixTextError, TextLocation};
+pub use self::textline::PixTextlineError;
+
 use leptonica_sys::{
-    l_float32, l_int32, l_uint32, pixClone, pixDestroy, pixGetData, pixGetDepth, pixGetHeight,
-    pixGetWidth, pixRead, pixReadMem, pixReadWithHint, pixScaleGeneral, pixTransferAllData,
+    l_float32, l_int32, l_ok, l_uint32, pixClone, pixConvertToDPix, pixConvertToFPix, pixCreate,
+    pixDestroy, pixGetColormap, pixGetData, pixGetDepth, pixGetHeight, pixGetPixel, pixGetWidth,
+    pixRead, pixReadMem, pixReadWithHint, pixScaleGeneral, pixSetPixel, pixTransferAllData,
 };
 
-use crate::memory::{LeptonicaClone, LeptonicaDestroy, RefCountedExclusive};
+use crate::memory::{BorrowedFrom, LeptonicaClone, LeptonicaDestroy, RefCountedExclusive};
+use crate::message::{capture, describe};
+use crate::{Dpix, Fpix, PixColormap};
 use std::convert::{AsRef, Infallible, TryInto};
 use std::{ffi::CStr, num::TryFromIntError};
 use thiserror::Error;
@@ -15,8 +52,8 @@ pub struct Pix(*mut leptonica_sys::Pix);
 /// Error returned by Pix::read_mem
 #[derive(Debug, Error, PartialEq)]
 pub enum PixReadMemError {
-    #[error("Pix::read_mem returned null")]
-    NullPtr,
+    #[error("Pix::read_mem returned null{}", describe(messages))]
+    NullPtr { messages: Vec<String> },
     #[error("Failed to convert image size")]
     ImageSizeConversion(#[from] TryFromIntError),
 }
@@ -27,10 +64,12 @@ impl From<Infallible> for PixReadMemError {
     }
 }
 
-/// Error returned by Pix::read
+/// Error returned by Pix::read, with the Leptonica messages written meanwhile
 #[derive(Debug, Error)]
-#[error("Pix::read returned null")]
-pub struct PixReadError();
+#[error("Pix::read returned null{}", describe(messages))]
+pub struct PixReadError {
+    pub messages: Vec<String>,
+}
 
 #[derive(Debug, Error, PartialEq)]
 pub enum PixManipError {
@@ -76,13 +115,32 @@ impl Pix {
         Self(ptr)
     }
 
+    /// Wrapper for [`pixCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix1.c)
+    ///
+    /// Input: width, height, depth (1, 2, 4, 8, 16 or 32). The image data is initialized to 0.
+    pub fn create(
+        width: l_int32,
+        height: l_int32,
+        depth: l_int32,
+    ) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { pixCreate(width, height, depth) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
     /// Wrapper for [`pixRead`](https://tpgit.github.io/Leptonica/leptprotos_8h.html#a84634846cbb5e01df667d6e9241dfc53)
     ///
     /// Read an image from a filename
+    ///
+    /// Once messages are routed with [`crate::message::route_to_log`], the error holds
+    /// Leptonica's reason for a failure.
     pub fn read(filename: &CStr) -> Result<RefCountedExclusive<Self>, PixReadError> {
-        let ptr = unsafe { pixRead(filename.as_ptr()) };
+        let (ptr, messages) = capture(|| unsafe { pixRead(filename.as_ptr()) });
         if ptr.is_null() {
-            Err(PixReadError())
+            Err(PixReadError { messages })
         } else {
             Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
         }
@@ -91,10 +149,14 @@ impl Pix {
     /// Wrapper for [`pixReadMem`](https://tpgit.github.io/Leptonica/leptprotos_8h.html#a027a927dc3438192e3bdae8c219d7f6a)
     ///
     /// Read an image from memory
+    ///
+    /// Once messages are routed with [`crate::message::route_to_log`], the error holds
+    /// Leptonica's reason for a failure.
     pub fn read_mem(img: &[u8]) -> Result<RefCountedExclusive<Self>, PixReadMemError> {
-        let ptr = unsafe { pixReadMem(img.as_ptr(), img.len().try_into()?) };
+        let size = img.len().try_into()?;
+        let (ptr, messages) = capture(|| unsafe { pixReadMem(img.as_ptr(), size) });
         if ptr.is_null() {
-            Err(PixReadMemError::NullPtr)
+            Err(PixReadMemError::NullPtr { messages })
         } else {
             Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
         }
@@ -110,9 +172,10 @@ impl Pix {
         filename: &CStr,
         hint: u32,
     ) -> Result<RefCountedExclusive<Self>, PixReadError> {
-        let ptr = unsafe { pixReadWithHint(filename.as_ptr(), hint as i32) };
+        let (ptr, messages) =
+            capture(|| unsafe { pixReadWithHint(filename.as_ptr(), hint as i32) });
         if ptr.is_null() {
-            Err(PixReadError())
+            Err(PixReadError { messages })
         } else {
             Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
         }
@@ -165,6 +228,98 @@ impl Pix {
     pub fn get_data(&self) -> *mut l_uint32 {
         unsafe { pixGetData(self.0) }
     }
+
+    /// Wrapper for [`pixGetPixel`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix2.c)
+    ///
+    /// Returns `None` if (x, y) is outside the image.
+    pub fn get_pixel(&self, x: l_int32, y: l_int32) -> Option<l_uint32> {
+        if x < 0 || y < 0 || x >= self.get_width() || y >= self.get_height() {
+            return None;
+        }
+        let mut val = 0;
+        if unsafe { pixGetPixel(self.0, x, y, &mut val) } != 0 {
+            None
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Wrapper for [`pixSetPixel`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix2.c)
+    pub fn set_pixel(&mut self, x: l_int32, y: l_int32, val: l_uint32) -> l_ok {
+        unsafe { pixSetPixel(self.0, x, y, val) }
+    }
+
+    /// Wrapper for [`pixGetColormap`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix1.c)
+    ///
+    /// Returns `None` if the image has no colormap.
+    pub fn get_colormap(&self) -> Option<BorrowedFrom<'_, PixColormap>> {
+        let ptr = unsafe { pixGetColormap(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { BorrowedFrom::new(PixColormap::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixConvertToFPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// Use `ncomps` 3 to keep the components of an RGB image, otherwise 1.
+    pub fn convert_to_fpix(&self, ncomps: l_int32) -> Option<RefCountedExclusive<Fpix>> {
+        let ptr = unsafe { pixConvertToFPix(self.0, ncomps) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Fpix::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixConvertToDPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/fpix2.c)
+    ///
+    /// Use `ncomps` 3 to keep the components of an RGB image, otherwise 1.
+    pub fn convert_to_dpix(&self, ncomps: l_int32) -> Option<RefCountedExclusive<Dpix>> {
+        let ptr = unsafe { pixConvertToDPix(self.0, ncomps) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Dpix::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Checks the depth is one of `depths`, otherwise returns the error built by `unsupported`
+    pub(crate) fn check_depth<E>(
+        &self,
+        depths: &[l_int32],
+        unsupported: impl FnOnce(l_int32) -> E,
+    ) -> Result<(), E> {
+        let depth = self.get_depth();
+        if depths.contains(&depth) {
+            Ok(())
+        } else {
+            Err(unsupported(depth))
+        }
+    }
+
+    /// Takes ownership of a Pix returned by Leptonica, or returns `null_error` if it is null
+    pub(crate) fn from_result<E>(
+        ptr: *mut leptonica_sys::Pix,
+        null_error: E,
+    ) -> Result<RefCountedExclusive<Self>, E> {
+        if ptr.is_null() {
+            Err(null_error)
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+}
+
+impl PartialEq for Pix {
+    /// Pixel equality, see [`crate::compare::equal`]
+    ///
+    /// Images are equal when they have the same size and pixel values, even if they are
+    /// different Leptonica structures. Images Leptonica fails to compare are not equal.
+    fn eq(&self, other: &Self) -> bool {
+        crate::compare::equal(self, other).unwrap_or(false)
+    }
 }
 
 impl LeptonicaDestroy for Pix {
@@ -191,7 +346,10 @@ mod tests {
 
     #[test]
     fn read_mem_error_test() {
-        assert_eq!(Pix::read_mem(&[]).err(), Some(PixReadMemError::NullPtr));
+        assert!(matches!(
+            Pix::read_mem(&[]).err(),
+            Some(PixReadMemError::NullPtr { .. })
+        ));
     }
 
     #[test]
@@ -220,6 +378,16 @@ mod tests {
         assert_eq!(pix.get_depth(), 32);
     }
 
+    #[test]
+    fn create_test() {
+        let mut pix = Pix::create(3, 2, 8).unwrap();
+        assert_eq!(pix.get_depth(), 8);
+        assert_eq!(pix.get_pixel(2, 1), Some(0));
+        pix.set_pixel(2, 1, 77);
+        assert_eq!(pix.get_pixel(2, 1), Some(77));
+        assert_eq!(pix.get_pixel(3, 1), None);
+    }
+
     #[test]
     fn clone_test() {
         let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
@@ -0,0 +1,288 @@
+use super::Pix;
+use crate::memory::RefCountedExclusive;
+
+use leptonica_sys::{
+    l_int32, pixAddGray, pixAnd, pixInvert, pixMinOrMax, pixOr, pixSubtract, pixSubtractGray,
+    pixXor, L_CHOOSE_MAX, L_CHOOSE_MIN,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix arithmetic and logical methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixArithError {
+    #[error("Pix depths {0} and {1} differ")]
+    DepthMismatch(l_int32, l_int32),
+    #[error("Pix sizes {0:?} and {1:?} differ")]
+    SizeMismatch((l_int32, l_int32), (l_int32, l_int32)),
+    #[error("Pix depth {0} is not supported, expected 8, 16 or 32")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix arithmetic returned null")]
+    NullPtr,
+}
+
+/// Which value of each pixel pair to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinOrMax {
+    /// `L_CHOOSE_MIN`
+    Min,
+    /// `L_CHOOSE_MAX`
+    Max,
+}
+
+impl MinOrMax {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Min => L_CHOOSE_MIN,
+            Self::Max => L_CHOOSE_MAX,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+type BinaryOp = unsafe extern "C" fn(
+    *mut leptonica_sys::Pix,
+    *mut leptonica_sys::Pix,
+    *mut leptonica_sys::Pix,
+) -> *mut leptonica_sys::Pix;
+
+impl Pix {
+    /// Wrapper for [`pixAnd`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Bitwise AND of two images of the same size and depth.
+    pub fn and(&self, other: &Pix) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.binary_op(other, pixAnd)
+    }
+
+    /// Wrapper for [`pixOr`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Bitwise OR of two images of the same size and depth.
+    pub fn or(&self, other: &Pix) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.binary_op(other, pixOr)
+    }
+
+    /// Wrapper for [`pixXor`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Bitwise XOR of two images of the same size and depth.
+    pub fn xor(&self, other: &Pix) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.binary_op(other, pixXor)
+    }
+
+    /// Wrapper for [`pixSubtract`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Bitwise set subtraction (`self AND NOT other`) of two images of the same size and depth.
+    pub fn subtract(&self, other: &Pix) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.binary_op(other, pixSubtract)
+    }
+
+    /// Wrapper for [`pixInvert`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    pub fn invert(&self) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        Self::from_result(
+            unsafe { pixInvert(std::ptr::null_mut(), self.0) },
+            PixArithError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixAddGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixarith.c)
+    ///
+    /// Input: 8, 16 or 32 bpp. Sums are clipped to the maximum value of the depth.
+    pub fn add_gray(&self, other: &Pix) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.check_depth(&[8, 16, 32], PixArithError::UnsupportedDepth)?;
+        self.binary_op(other, pixAddGray)
+    }
+
+    /// Wrapper for [`pixSubtractGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixarith.c)
+    ///
+    /// Input: 8, 16 or 32 bpp. Differences are clipped to 0.
+    pub fn subtract_gray(&self, other: &Pix) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.check_depth(&[8, 16, 32], PixArithError::UnsupportedDepth)?;
+        self.binary_op(other, pixSubtractGray)
+    }
+
+    /// Wrapper for [`pixMinOrMax`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixarith.c)
+    ///
+    /// Input: 8, 16 or 32 bpp.
+    pub fn min_or_max(
+        &self,
+        other: &Pix,
+        r#type: MinOrMax,
+    ) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.check_depth(&[8, 16, 32], PixArithError::UnsupportedDepth)?;
+        self.check_same_geometry(other)?;
+        Self::from_result(
+            unsafe { pixMinOrMax(std::ptr::null_mut(), self.0, other.0, r#type.to_leptonica()) },
+            PixArithError::NullPtr,
+        )
+    }
+
+    /// In place variant of [`Pix::and`]
+    pub fn and_in_place(&mut self, other: &Pix) -> Result<(), PixArithError> {
+        self.binary_op_in_place(other, pixAnd)
+    }
+
+    /// In place variant of [`Pix::or`]
+    pub fn or_in_place(&mut self, other: &Pix) -> Result<(), PixArithError> {
+        self.binary_op_in_place(other, pixOr)
+    }
+
+    /// In place variant of [`Pix::xor`]
+    pub fn xor_in_place(&mut self, other: &Pix) -> Result<(), PixArithError> {
+        self.binary_op_in_place(other, pixXor)
+    }
+
+    /// In place variant of [`Pix::subtract`]
+    pub fn subtract_in_place(&mut self, other: &Pix) -> Result<(), PixArithError> {
+        self.binary_op_in_place(other, pixSubtract)
+    }
+
+    /// In place variant of [`Pix::invert`]
+    pub fn invert_in_place(&mut self) -> Result<(), PixArithError> {
+        if unsafe { pixInvert(self.0, self.0) }.is_null() {
+            Err(PixArithError::NullPtr)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// In place variant of [`Pix::add_gray`]
+    pub fn add_gray_in_place(&mut self, other: &Pix) -> Result<(), PixArithError> {
+        self.check_depth(&[8, 16, 32], PixArithError::UnsupportedDepth)?;
+        self.binary_op_in_place(other, pixAddGray)
+    }
+
+    /// In place variant of [`Pix::subtract_gray`]
+    pub fn subtract_gray_in_place(&mut self, other: &Pix) -> Result<(), PixArithError> {
+        self.check_depth(&[8, 16, 32], PixArithError::UnsupportedDepth)?;
+        self.binary_op_in_place(other, pixSubtractGray)
+    }
+
+    /// In place variant of [`Pix::min_or_max`]
+    pub fn min_or_max_in_place(
+        &mut self,
+        other: &Pix,
+        r#type: MinOrMax,
+    ) -> Result<(), PixArithError> {
+        self.check_depth(&[8, 16, 32], PixArithError::UnsupportedDepth)?;
+        self.check_same_geometry(other)?;
+        if unsafe { pixMinOrMax(self.0, self.0, other.0, r#type.to_leptonica()) }.is_null() {
+            Err(PixArithError::NullPtr)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn binary_op(
+        &self,
+        other: &Pix,
+        op: BinaryOp,
+    ) -> Result<RefCountedExclusive<Pix>, PixArithError> {
+        self.check_same_geometry(other)?;
+        Self::from_result(
+            unsafe { op(std::ptr::null_mut(), self.0, other.0) },
+            PixArithError::NullPtr,
+        )
+    }
+
+    fn binary_op_in_place(&mut self, other: &Pix, op: BinaryOp) -> Result<(), PixArithError> {
+        self.check_same_geometry(other)?;
+        if unsafe { op(self.0, self.0, other.0) }.is_null() {
+            Err(PixArithError::NullPtr)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_same_geometry(&self, other: &Pix) -> Result<(), PixArithError> {
+        let (depth, other_depth) = (self.get_depth(), other.get_depth());
+        if depth != other_depth {
+            return Err(PixArithError::DepthMismatch(depth, other_depth));
+        }
+        let size = (self.get_width(), self.get_height());
+        let other_size = (other.get_width(), other.get_height());
+        if size != other_size {
+            return Err(PixArithError::SizeMismatch(size, other_size));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_image(depth: l_int32, values: &[u32]) -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(values.len() as l_int32, 1, depth).unwrap();
+        for (x, &val) in values.iter().enumerate() {
+            pix.set_pixel(x as l_int32, 0, val);
+        }
+        pix
+    }
+
+    fn row_values(pix: &Pix) -> Vec<u32> {
+        (0..pix.get_width())
+            .map(|x| pix.get_pixel(x, 0).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn logical_test() {
+        let a = row_image(1, &[1, 1, 0, 0]);
+        let b = row_image(1, &[1, 0, 1, 0]);
+        assert_eq!(row_values(&a.and(&b).unwrap()), [1, 0, 0, 0]);
+        assert_eq!(row_values(&a.or(&b).unwrap()), [1, 1, 1, 0]);
+        assert_eq!(row_values(&a.xor(&b).unwrap()), [0, 1, 1, 0]);
+        assert_eq!(row_values(&a.subtract(&b).unwrap()), [0, 1, 0, 0]);
+        assert_eq!(row_values(&a.invert().unwrap()), [0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn gray_test() {
+        let a = row_image(8, &[100, 200, 30]);
+        let b = row_image(8, &[100, 100, 50]);
+        assert_eq!(row_values(&a.add_gray(&b).unwrap()), [200, 255, 80]);
+        assert_eq!(row_values(&a.subtract_gray(&b).unwrap()), [0, 100, 0]);
+        assert_eq!(
+            row_values(&a.min_or_max(&b, MinOrMax::Min).unwrap()),
+            [100, 100, 30]
+        );
+        assert_eq!(
+            row_values(&a.min_or_max(&b, MinOrMax::Max).unwrap()),
+            [100, 200, 50]
+        );
+    }
+
+    #[test]
+    fn in_place_test() {
+        let mut a = row_image(1, &[1, 1, 0, 0]);
+        let b = row_image(1, &[1, 0, 1, 0]);
+        a.xor_in_place(&b).unwrap();
+        assert_eq!(row_values(&a), [0, 1, 1, 0]);
+        a.invert_in_place().unwrap();
+        assert_eq!(row_values(&a), [1, 0, 0, 1]);
+
+        let mut c = row_image(8, &[100, 200]);
+        c.add_gray_in_place(&row_image(8, &[10, 100])).unwrap();
+        assert_eq!(row_values(&c), [110, 255]);
+        c.min_or_max_in_place(&row_image(8, &[120, 0]), MinOrMax::Max)
+            .unwrap();
+        assert_eq!(row_values(&c), [120, 255]);
+    }
+
+    #[test]
+    fn mismatch_test() {
+        let a = row_image(1, &[1, 1, 0, 0]);
+        assert_eq!(
+            a.and(&row_image(8, &[1, 1, 0, 0])).err(),
+            Some(PixArithError::DepthMismatch(1, 8))
+        );
+        assert_eq!(
+            a.or(&row_image(1, &[1, 1, 0])).err(),
+            Some(PixArithError::SizeMismatch((4, 1), (3, 1)))
+        );
+        assert_eq!(
+            a.add_gray(&row_image(1, &[1, 1, 0, 0])).err(),
+            Some(PixArithError::UnsupportedDepth(1))
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use super::Pix;
+use crate::{memory::RefCountedExclusive, Box};
+
+use leptonica_sys::{
+    boxDestroy, l_int32, l_uint32, pixAddBorderGeneral, pixClipRectangle, pixClipToForeground,
+    pixDestroy, pixRasterop, pixRemoveBorderGeneral, PIX_CLR, PIX_DST, PIX_SET, PIX_SRC,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix clipping, border and rasterop methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixClipError {
+    #[error("Pix depth {0} is not supported, expected 1")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix has no foreground pixels")]
+    NoForeground,
+    #[error("Pix rasterop failed")]
+    Rasterop,
+    #[error("Pix clipping returned null")]
+    NullPtr,
+}
+
+/// Rasterop operation combining the source (`src`) with the destination (`dst`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasterOp {
+    /// `PIX_CLR`: clear the destination
+    Clear,
+    /// `PIX_SET`: set the destination
+    Set,
+    /// `PIX_SRC`: copy the source
+    Src,
+    /// `PIX_NOT(PIX_DST)`: invert the destination
+    NotDst,
+    /// `PIX_NOT(PIX_SRC)`: copy the inverted source
+    NotSrc,
+    /// `PIX_PAINT`: `src | dst`
+    Paint,
+    /// `PIX_MASK`: `src & dst`
+    Mask,
+    /// `PIX_SUBTRACT`: `dst & !src`
+    Subtract,
+    /// `PIX_XOR`: `src ^ dst`
+    Xor,
+}
+
+impl RasterOp {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        // PIX_NOT(op) is a function-like macro: ((op) ^ 0x1e), where 0x1e is PIX_SET
+        let not = |op| op ^ PIX_SET;
+        match self {
+            Self::Clear => PIX_CLR,
+            Self::Set => PIX_SET,
+            Self::Src => PIX_SRC,
+            Self::NotDst => not(PIX_DST),
+            Self::NotSrc => not(PIX_SRC),
+            Self::Paint => PIX_SRC | PIX_DST,
+            Self::Mask => PIX_SRC & PIX_DST,
+            Self::Subtract => PIX_DST & not(PIX_SRC),
+            Self::Xor => PIX_SRC ^ PIX_DST,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixClipRectangle`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix5.c)
+    ///
+    /// Returns the clipped image and the box actually clipped, after clipping `r#box` to the image.
+    pub fn clip_rectangle(
+        &self,
+        r#box: &Box,
+    ) -> Result<(RefCountedExclusive<Pix>, RefCountedExclusive<Box>), PixClipError> {
+        let box_ptr = AsRef::<leptonica_sys::Box>::as_ref(r#box) as *const _ as *mut _;
+        let mut boxc = std::ptr::null_mut();
+        let mut pixd = unsafe { pixClipRectangle(self.0, box_ptr, &mut boxc) };
+        if pixd.is_null() || boxc.is_null() {
+            unsafe {
+                pixDestroy(&mut pixd);
+                boxDestroy(&mut boxc);
+            }
+            Err(PixClipError::NullPtr)
+        } else {
+            Ok(unsafe {
+                (
+                    RefCountedExclusive::new(Pix(pixd)),
+                    RefCountedExclusive::new(Box::new_from_pointer(boxc)),
+                )
+            })
+        }
+    }
+
+    /// Wrapper for [`pixClipToForeground`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix5.c)
+    ///
+    /// Input: 1 bpp. Returns the image clipped to the bounding box of the foreground, and that box.
+    pub fn clip_to_foreground(
+        &self,
+    ) -> Result<(RefCountedExclusive<Pix>, RefCountedExclusive<Box>), PixClipError> {
+        self.check_depth(&[1], PixClipError::UnsupportedDepth)?;
+        let mut pixd = std::ptr::null_mut();
+        let mut r#box = std::ptr::null_mut();
+        let result = unsafe { pixClipToForeground(self.0, &mut pixd, &mut r#box) };
+        if result != 0 || pixd.is_null() || r#box.is_null() {
+            unsafe {
+                pixDestroy(&mut pixd);
+                boxDestroy(&mut r#box);
+            }
+            Err(PixClipError::NoForeground)
+        } else {
+            Ok(unsafe {
+                (
+                    RefCountedExclusive::new(Pix(pixd)),
+                    RefCountedExclusive::new(Box::new_from_pointer(r#box)),
+                )
+            })
+        }
+    }
+
+    /// Wrapper for [`pixAddBorderGeneral`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix2.c)
+    ///
+    /// Input: border widths and the pixel value to fill them with.
+    pub fn add_border(
+        &self,
+        left: l_int32,
+        right: l_int32,
+        top: l_int32,
+        bottom: l_int32,
+        value: l_uint32,
+    ) -> Result<RefCountedExclusive<Pix>, PixClipError> {
+        Self::from_result(
+            unsafe { pixAddBorderGeneral(self.0, left, right, top, bottom, value) },
+            PixClipError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixRemoveBorderGeneral`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix2.c)
+    pub fn remove_border(
+        &self,
+        left: l_int32,
+        right: l_int32,
+        top: l_int32,
+        bottom: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixClipError> {
+        Self::from_result(
+            unsafe { pixRemoveBorderGeneral(self.0, left, right, top, bottom) },
+            PixClipError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixRasterop`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/rop.c)
+    ///
+    /// Combines the rectangle of `src` at (sx, sy) into the `dst_box` rectangle of this image.
+    /// Both images must have the same depth, unless the op is `Clear`, `Set` or `NotDst`.
+    pub fn rasterop(
+        &mut self,
+        dst_box: &Box,
+        op: RasterOp,
+        src: &Pix,
+        sx: l_int32,
+        sy: l_int32,
+    ) -> Result<(), PixClipError> {
+        let (mut dx, mut dy, mut dw, mut dh) = (0, 0, 0, 0);
+        dst_box.get_geometry(Some(&mut dx), Some(&mut dy), Some(&mut dw), Some(&mut dh));
+        let result =
+            unsafe { pixRasterop(self.0, dx, dy, dw, dh, op.to_leptonica(), src.0, sx, sy) };
+        if result != 0 {
+            Err(PixClipError::Rasterop)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn geometry(r#box: &Box) -> (l_int32, l_int32, l_int32, l_int32) {
+        let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+        r#box.get_geometry(Some(&mut x), Some(&mut y), Some(&mut w), Some(&mut h));
+        (x, y, w, h)
+    }
+
+    #[test]
+    fn clip_rectangle_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let r#box = Box::create_valid(10, 5, 50, 100).unwrap();
+        let (clipped, boxc) = pix.clip_rectangle(&r#box).unwrap();
+        assert_eq!(geometry(&boxc), (10, 5, 50, 18));
+        assert_eq!(clipped.get_width(), 50);
+        assert_eq!(clipped.get_height(), 18);
+    }
+
+    #[test]
+    fn clip_to_foreground_test() {
+        let mut pix = Pix::create(10, 10, 1).unwrap();
+        assert_eq!(
+            pix.clip_to_foreground().err(),
+            Some(PixClipError::NoForeground)
+        );
+        pix.set_pixel(3, 4, 1);
+        pix.set_pixel(6, 7, 1);
+        let (clipped, r#box) = pix.clip_to_foreground().unwrap();
+        assert_eq!(geometry(&r#box), (3, 4, 4, 4));
+        assert_eq!(clipped.get_width(), 4);
+    }
+
+    #[test]
+    fn border_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let bordered = pix.add_border(1, 2, 3, 4, 0xffffff00).unwrap();
+        assert_eq!(bordered.get_width(), 203);
+        assert_eq!(bordered.get_height(), 30);
+        assert_eq!(bordered.get_pixel(0, 0), Some(0xffffff00));
+        let removed = bordered.remove_border(1, 2, 3, 4).unwrap();
+        assert_eq!(removed.get_width(), 200);
+        assert_eq!(removed.get_height(), 23);
+    }
+
+    #[test]
+    fn rasterop_test() {
+        let mut dst = Pix::create(4, 4, 8).unwrap();
+        let mut src = Pix::create(2, 2, 8).unwrap();
+        for (x, y) in [(0, 0), (0, 1), (1, 0), (1, 1)].iter() {
+            src.set_pixel(*x, *y, 9);
+        }
+        let r#box = Box::create_valid(1, 1, 2, 2).unwrap();
+        dst.rasterop(&r#box, RasterOp::Src, &src, 0, 0).unwrap();
+        assert_eq!(dst.get_pixel(0, 0), Some(0));
+        assert_eq!(dst.get_pixel(1, 1), Some(9));
+        assert_eq!(dst.get_pixel(2, 2), Some(9));
+        assert_eq!(dst.get_pixel(3, 3), Some(0));
+        dst.rasterop(&r#box, RasterOp::Clear, &src, 0, 0).unwrap();
+        assert_eq!(dst.get_pixel(1, 1), Some(0));
+    }
+
+    #[test]
+    fn raster_op_values_test() {
+        assert_eq!(RasterOp::Paint.to_leptonica(), 0x1c);
+        assert_eq!(RasterOp::Mask.to_leptonica(), 0x10);
+        assert_eq!(RasterOp::Subtract.to_leptonica(), 0x04);
+        assert_eq!(RasterOp::Xor.to_leptonica(), 0x0c);
+        assert_eq!(RasterOp::NotSrc.to_leptonica(), 0x06);
+    }
+}
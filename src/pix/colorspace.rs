@@ -0,0 +1,284 @@
+use super::Pix;
+use crate::{memory::RefCountedExclusive, Fpixa};
+
+use leptonica_sys::{
+    l_int32, pixColorSegment, pixConvertHSVToRGB, pixConvertLABToRGB, pixConvertRGBToHSV,
+    pixConvertRGBToLAB, pixConvertRGBToYUV, pixConvertYUVToRGB, pixCreateRGBImage,
+    pixGetRGBComponent, pixMakeRangeMaskHS, COLOR_BLUE, COLOR_GREEN, COLOR_RED, L_ALPHA_CHANNEL,
+    L_EXCLUDE_REGION, L_INCLUDE_REGION,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix color space methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixColorspaceError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix color space conversion returned null")]
+    NullPtr,
+}
+
+/// A component of an RGBA pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorComponent {
+    /// `COLOR_RED`
+    Red,
+    /// `COLOR_GREEN`
+    Green,
+    /// `COLOR_BLUE`
+    Blue,
+    /// `L_ALPHA_CHANNEL`
+    Alpha,
+}
+
+impl ColorComponent {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Red => COLOR_RED,
+            Self::Green => COLOR_GREEN,
+            Self::Blue => COLOR_BLUE,
+            Self::Alpha => L_ALPHA_CHANNEL,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+/// Whether a range mask selects the pixels inside or outside of the range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRegion {
+    /// `L_INCLUDE_REGION`
+    Include,
+    /// `L_EXCLUDE_REGION`
+    Exclude,
+}
+
+impl RangeRegion {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Include => L_INCLUDE_REGION,
+            Self::Exclude => L_EXCLUDE_REGION,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixConvertRGBToHSV`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: 32 bpp. The hue, saturation and value are stored in the red, green and blue
+    /// components. Hue is in the range 0 to 239.
+    pub fn convert_rgb_to_hsv(&self) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixConvertRGBToHSV(std::ptr::null_mut(), self.0) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixConvertHSVToRGB`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: 32 bpp HSV, as produced by [`Pix::convert_rgb_to_hsv`].
+    pub fn convert_hsv_to_rgb(&self) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixConvertHSVToRGB(std::ptr::null_mut(), self.0) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixConvertRGBToYUV`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: 32 bpp. Y, U and V are stored in the red, green and blue components.
+    pub fn convert_rgb_to_yuv(&self) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixConvertRGBToYUV(std::ptr::null_mut(), self.0) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixConvertYUVToRGB`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: 32 bpp YUV, as produced by [`Pix::convert_rgb_to_yuv`].
+    pub fn convert_yuv_to_rgb(&self) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixConvertYUVToRGB(std::ptr::null_mut(), self.0) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixConvertRGBToLAB`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: 32 bpp. Returns the L, A and B components as three Fpix.
+    pub fn convert_rgb_to_lab(&self) -> Result<RefCountedExclusive<Fpixa>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        let ptr = unsafe { pixConvertRGBToLAB(self.0) };
+        if ptr.is_null() {
+            Err(PixColorspaceError::NullPtr)
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Fpixa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixConvertLABToRGB`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: the L, A and B components, as produced by [`Pix::convert_rgb_to_lab`].
+    pub fn convert_lab_to_rgb(lab: &Fpixa) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        Self::from_result(
+            unsafe { pixConvertLABToRGB(*lab.as_ref()) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixGetRGBComponent`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix2.c)
+    ///
+    /// Input: 32 bpp, or colormapped. Returns the component as an 8 bpp image.
+    pub fn get_rgb_component(
+        &self,
+        component: ColorComponent,
+    ) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        Self::from_result(
+            unsafe { pixGetRGBComponent(self.0, component.to_leptonica()) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixCreateRGBImage`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix2.c)
+    ///
+    /// Input: the red, green and blue components, 8 bpp and of the same size.
+    pub fn create_rgb_image(
+        red: &Pix,
+        green: &Pix,
+        blue: &Pix,
+    ) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        red.check_depth(&[8], PixColorspaceError::UnsupportedDepth)?;
+        green.check_depth(&[8], PixColorspaceError::UnsupportedDepth)?;
+        blue.check_depth(&[8], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixCreateRGBImage(red.0, green.0, blue.0) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixColorSegment`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorseg.c)
+    ///
+    /// Input: 32 bpp, maxdist (largest distance of a pixel to its cluster color, eg 75),
+    /// maxcolors (largest number of colors in the first pass, eg 10), selsize (size of the
+    /// closing that cleans up the segmentation, 0 to skip), finalcolors (largest number of
+    /// colors kept, eg 3). Returns an 8 bpp colormapped image.
+    pub fn color_segment(
+        &self,
+        maxdist: l_int32,
+        maxcolors: l_int32,
+        selsize: l_int32,
+        finalcolors: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixColorSegment(self.0, maxdist, maxcolors, selsize, finalcolors, 0) },
+            PixColorspaceError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixMakeRangeMaskHS`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorspace.c)
+    ///
+    /// Input: 32 bpp RGB, the center and half width of the hue (0 to 239, wrapping around) and
+    /// saturation (0 to 255) ranges. Returns a 1 bpp mask of the pixels inside or outside of
+    /// the ranges.
+    pub fn make_range_mask_hs(
+        &self,
+        huecenter: l_int32,
+        huehw: l_int32,
+        satcenter: l_int32,
+        sathw: l_int32,
+        region: RangeRegion,
+    ) -> Result<RefCountedExclusive<Pix>, PixColorspaceError> {
+        self.check_depth(&[32], PixColorspaceError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe {
+                pixMakeRangeMaskHS(
+                    self.0,
+                    huecenter,
+                    huehw,
+                    satcenter,
+                    sathw,
+                    region.to_leptonica(),
+                )
+            },
+            PixColorspaceError::NullPtr,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color_image() -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(2, 1, 32).unwrap();
+        pix.set_pixel(0, 0, 0xff000000);
+        pix.set_pixel(1, 0, 0x0000ff00);
+        pix
+    }
+
+    #[test]
+    fn hsv_yuv_test() {
+        let pix = color_image();
+        let hsv = pix.convert_rgb_to_hsv().unwrap();
+        // Red has hue 0, full saturation and full value
+        assert_eq!(hsv.get_pixel(0, 0), Some(0x00ffff00));
+        assert!(*hsv.convert_hsv_to_rgb().unwrap() == *pix);
+
+        let yuv = pix.convert_rgb_to_yuv().unwrap();
+        assert_eq!(yuv.get_depth(), 32);
+        assert!(*yuv != *pix);
+    }
+
+    #[test]
+    fn lab_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let lab = pix.convert_rgb_to_lab().unwrap();
+        assert_eq!(lab.get_count(), 3);
+        assert_eq!(lab.get_fpix_cloned(0).unwrap().get_dimensions(), (200, 23));
+        assert!(lab.get_fpix_copied(3).is_none());
+        let rgb = Pix::convert_lab_to_rgb(&lab).unwrap();
+        assert_eq!(rgb.get_depth(), 32);
+    }
+
+    #[test]
+    fn component_test() {
+        let pix = color_image();
+        let red = pix.get_rgb_component(ColorComponent::Red).unwrap();
+        let green = pix.get_rgb_component(ColorComponent::Green).unwrap();
+        let blue = pix.get_rgb_component(ColorComponent::Blue).unwrap();
+        assert_eq!(red.get_depth(), 8);
+        assert_eq!(red.get_pixel(0, 0), Some(255));
+        assert_eq!(blue.get_pixel(0, 0), Some(0));
+        assert_eq!(blue.get_pixel(1, 0), Some(255));
+        let rgb = Pix::create_rgb_image(&red, &green, &blue).unwrap();
+        assert!(*rgb == *pix);
+        assert_eq!(
+            Pix::create_rgb_image(&pix, &green, &blue).err(),
+            Some(PixColorspaceError::UnsupportedDepth(32))
+        );
+    }
+
+    #[test]
+    fn segment_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let segmented = pix.color_segment(75, 10, 0, 3).unwrap();
+        assert_eq!(segmented.get_depth(), 8);
+        assert!(segmented.get_colormap().unwrap().get_count() <= 3);
+
+        let mask = color_image()
+            .make_range_mask_hs(0, 10, 255, 10, RangeRegion::Include)
+            .unwrap();
+        assert_eq!(mask.get_depth(), 1);
+        assert_eq!(mask.get_pixel(0, 0), Some(1));
+        assert_eq!(mask.get_pixel(1, 0), Some(0));
+    }
+}
@@ -0,0 +1,88 @@
+use super::Pix;
+use crate::{memory::RefCountedExclusive, Boxa};
+
+use leptonica_sys::{l_int32, pixConnComp, pixCountConnComp};
+use thiserror::Error;
+
+/// Error returned by the Pix connected component methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixConnCompError {
+    #[error("Pix depth {0} is not supported, expected 1")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix connected components failed")]
+    Failed,
+}
+
+/// Which neighbours of a pixel are connected to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Horizontal and vertical neighbours
+    Four,
+    /// Horizontal, vertical and diagonal neighbours
+    Eight,
+}
+
+impl Connectivity {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Four => 4,
+            Self::Eight => 8,
+        }
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixConnComp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/conncomp.c)
+    ///
+    /// Input: 1 bpp. Returns the bounding boxes of the connected components.
+    pub fn conn_comp(
+        &self,
+        connectivity: Connectivity,
+    ) -> Result<RefCountedExclusive<Boxa>, PixConnCompError> {
+        self.check_depth(&[1], PixConnCompError::UnsupportedDepth)?;
+        let ptr = unsafe { pixConnComp(self.0, std::ptr::null_mut(), connectivity.to_leptonica()) };
+        if ptr.is_null() {
+            Err(PixConnCompError::Failed)
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixCountConnComp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/conncomp.c)
+    ///
+    /// Input: 1 bpp. Returns the number of connected components.
+    pub fn count_conn_comp(&self, connectivity: Connectivity) -> Result<l_int32, PixConnCompError> {
+        self.check_depth(&[1], PixConnCompError::UnsupportedDepth)?;
+        let mut count = 0;
+        if unsafe { pixCountConnComp(self.0, connectivity.to_leptonica(), &mut count) } != 0 {
+            Err(PixConnCompError::Failed)
+        } else {
+            Ok(count)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conn_comp_test() {
+        // Two diagonally touching pixels
+        let mut pix = Pix::create(4, 4, 1).unwrap();
+        pix.set_pixel(1, 1, 1);
+        pix.set_pixel(2, 2, 1);
+        assert_eq!(pix.count_conn_comp(Connectivity::Four), Ok(2));
+        assert_eq!(pix.count_conn_comp(Connectivity::Eight), Ok(1));
+        let boxa = pix.conn_comp(Connectivity::Eight).unwrap();
+        assert_eq!(boxa.get_count(), 1);
+        let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+        boxa.get_box_copied(0).unwrap().get_geometry(
+            Some(&mut x),
+            Some(&mut y),
+            Some(&mut w),
+            Some(&mut h),
+        );
+        assert_eq!((x, y, w, h), (1, 1, 2, 2));
+    }
+}
@@ -0,0 +1,192 @@
+use super::Pix;
+use crate::{memory::RefCountedExclusive, Kernel};
+
+use leptonica_sys::{
+    l_float32, l_int32, pixBlockconv, pixConvolve, pixConvolveRGBSep, pixConvolveSep,
+};
+use thiserror::Error;
+
+/// Error returned by the Pix convolution methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixConvolveError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Failed to make the kernel")]
+    KernelError,
+    #[error("Pix convolution returned null")]
+    NullPtr,
+}
+
+impl Pix {
+    /// Wrapper for [`pixConvolve`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/convolve.c)
+    ///
+    /// Input: 8, 16 or 32 bpp without a colormap, outdepth (8, 16 or 32).
+    /// When `normalize` is set, the kernel is normalized to unit sum first.
+    pub fn convolve(
+        &self,
+        kernel: &Kernel,
+        outdepth: l_int32,
+        normalize: bool,
+    ) -> Result<RefCountedExclusive<Pix>, PixConvolveError> {
+        self.check_depth(&[8, 16, 32], PixConvolveError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixConvolve(self.0, *kernel.as_ref(), outdepth, normalize.into()) },
+            PixConvolveError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixConvolveSep`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/convolve.c)
+    ///
+    /// Convolves with the horizontal kernel `kelx` and then the vertical kernel `kely`.
+    pub fn convolve_sep(
+        &self,
+        kelx: &Kernel,
+        kely: &Kernel,
+        outdepth: l_int32,
+        normalize: bool,
+    ) -> Result<RefCountedExclusive<Pix>, PixConvolveError> {
+        self.check_depth(&[8, 16, 32], PixConvolveError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe {
+                pixConvolveSep(
+                    self.0,
+                    *kelx.as_ref(),
+                    *kely.as_ref(),
+                    outdepth,
+                    normalize.into(),
+                )
+            },
+            PixConvolveError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixBlockconv`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/convolve.c)
+    ///
+    /// Block convolution (box blur) over a `2 * wc + 1` by `2 * hc + 1` window.
+    /// Input: 8 or 32 bpp, or 2, 4 or 8 bpp with a colormap.
+    pub fn block_conv(
+        &self,
+        wc: l_int32,
+        hc: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixConvolveError> {
+        let depths: &[l_int32] = if self.get_colormap().is_some() {
+            &[2, 4, 8]
+        } else {
+            &[8, 32]
+        };
+        self.check_depth(depths, PixConvolveError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixBlockconv(self.0, wc, hc) },
+            PixConvolveError::NullPtr,
+        )
+    }
+
+    /// Gaussian blur using separable kernels that extend to about 2.5 standard deviations.
+    ///
+    /// Input: 8 bpp gray or 32 bpp RGB.
+    pub fn gaussian_blur(
+        &self,
+        sigma: l_float32,
+    ) -> Result<RefCountedExclusive<Pix>, PixConvolveError> {
+        let half = (2.5 * sigma).ceil().max(1.0) as l_int32;
+        let (kelx, kely) = Kernel::make_gaussian_sep(half, half, sigma, 1.0)
+            .ok_or(PixConvolveError::KernelError)?;
+        match self.get_depth() {
+            8 => self.convolve_sep(&kelx, &kely, 8, true),
+            32 => Self::from_result(
+                unsafe { pixConvolveRGBSep(self.0, *kelx.as_ref(), *kely.as_ref()) },
+                PixConvolveError::NullPtr,
+            ),
+            depth => Err(PixConvolveError::UnsupportedDepth(depth)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptonica_sys::{pixConvertTo1, pixConvertTo8};
+    use std::ffi::CStr;
+
+    fn gray_image() -> RefCountedExclusive<Pix> {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        unsafe { RefCountedExclusive::new(Pix(pixConvertTo8(pix.0, 0))) }
+    }
+
+    /// 9 by 9 black 8 bpp image with a single white pixel in the center
+    fn point_image() -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(9, 9, 8).unwrap();
+        pix.set_pixel(4, 4, 255);
+        pix
+    }
+
+    #[test]
+    fn convolve_test() {
+        let kdata = CStr::from_bytes_with_nul(b"1 1 1 1 1 1 1 1 1\0").unwrap();
+        let kel = Kernel::create_from_string(3, 3, 1, 1, kdata).unwrap();
+        let pix = gray_image().convolve(&kel, 16, true).unwrap();
+        assert_eq!(pix.get_width(), 200);
+        assert_eq!(pix.get_depth(), 16);
+
+        let blurred = point_image().convolve(&kel, 8, true).unwrap();
+        assert!(blurred.get_pixel(4, 4).unwrap() < 255);
+        assert!(blurred.get_pixel(3, 4).unwrap() > 0);
+        assert!(blurred.get_pixel(5, 5).unwrap() > 0);
+        assert_eq!(blurred.get_pixel(0, 0), Some(0));
+    }
+
+    #[test]
+    fn convolve_sep_test() {
+        let (kelx, kely) = Kernel::make_gaussian_sep(2, 2, 1.0, 1.0).unwrap();
+        let pix = gray_image().convolve_sep(&kelx, &kely, 8, true).unwrap();
+        assert_eq!(pix.get_height(), 23);
+        assert_eq!(pix.get_depth(), 8);
+    }
+
+    #[test]
+    fn convolve_unsupported_depth_test() {
+        let pix = gray_image();
+        let pix = unsafe { RefCountedExclusive::new(Pix(pixConvertTo1(pix.0, 128))) };
+        let kel = Kernel::make_gaussian(1, 1, 1.0, 1.0).unwrap();
+        assert_eq!(
+            pix.convolve(&kel, 8, true).err(),
+            Some(PixConvolveError::UnsupportedDepth(1))
+        );
+        assert_eq!(
+            pix.gaussian_blur(1.0).err(),
+            Some(PixConvolveError::UnsupportedDepth(1))
+        );
+        assert_eq!(
+            pix.block_conv(1, 1).err(),
+            Some(PixConvolveError::UnsupportedDepth(1))
+        );
+        let pix16 = Pix::create(9, 9, 16).unwrap();
+        assert_eq!(
+            pix16.block_conv(1, 1).err(),
+            Some(PixConvolveError::UnsupportedDepth(16))
+        );
+    }
+
+    #[test]
+    fn block_conv_test() {
+        let pix = gray_image().block_conv(2, 2).unwrap();
+        assert_eq!(pix.get_width(), 200);
+        assert_eq!(pix.get_depth(), 8);
+
+        let blurred = point_image().block_conv(1, 1).unwrap();
+        assert!(blurred.get_pixel(4, 4).unwrap() < 255);
+        assert!(blurred.get_pixel(4, 3).unwrap() > 0);
+        assert!(blurred.get_pixel(3, 5).unwrap() > 0);
+        assert_eq!(blurred.get_pixel(0, 8), Some(0));
+    }
+
+    #[test]
+    fn gaussian_blur_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let blurred = pix.gaussian_blur(1.5).unwrap();
+        assert_eq!(blurred.get_width(), 200);
+        assert_eq!(blurred.get_depth(), 32);
+        let blurred = gray_image().gaussian_blur(1.5).unwrap();
+        assert_eq!(blurred.get_depth(), 8);
+    }
+}
@@ -0,0 +1,127 @@
+use super::Pix;
+use crate::memory::RefCountedExclusive;
+
+use leptonica_sys::{
+    l_int32, pixSobelEdgeFilter, pixTwoSidedEdgeFilter, L_ALL_EDGES, L_HORIZONTAL_EDGES,
+    L_VERTICAL_EDGES,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix edge methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixEdgeError {
+    #[error("Pix depth {0} is not supported, expected 8")]
+    UnsupportedDepth(l_int32),
+    #[error("Edge orientation {0:?} is not supported")]
+    UnsupportedOrientation(EdgeOrientation),
+    #[error("Pix edge filter returned null")]
+    NullPtr,
+}
+
+/// Orientation of the edges to find
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeOrientation {
+    /// `L_HORIZONTAL_EDGES`
+    Horizontal,
+    /// `L_VERTICAL_EDGES`
+    Vertical,
+    /// `L_ALL_EDGES`
+    All,
+}
+
+impl EdgeOrientation {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Horizontal => L_HORIZONTAL_EDGES,
+            Self::Vertical => L_VERTICAL_EDGES,
+            Self::All => L_ALL_EDGES,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixSobelEdgeFilter`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/edge.c)
+    ///
+    /// Input: 8 bpp. Edges are bright in the 8 bpp result.
+    pub fn sobel_edges(
+        &self,
+        orientation: EdgeOrientation,
+    ) -> Result<RefCountedExclusive<Pix>, PixEdgeError> {
+        self.check_depth(&[8], PixEdgeError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixSobelEdgeFilter(self.0, orientation.to_leptonica()) },
+            PixEdgeError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixTwoSidedEdgeFilter`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/edge.c)
+    ///
+    /// Input: 8 bpp, horizontal or vertical orientation.
+    /// Only responds where the gradient has the same sign on both sides of a pixel.
+    pub fn two_sided_edges(
+        &self,
+        orientation: EdgeOrientation,
+    ) -> Result<RefCountedExclusive<Pix>, PixEdgeError> {
+        self.check_depth(&[8], PixEdgeError::UnsupportedDepth)?;
+        if orientation == EdgeOrientation::All {
+            return Err(PixEdgeError::UnsupportedOrientation(orientation));
+        }
+        Self::from_result(
+            unsafe { pixTwoSidedEdgeFilter(self.0, orientation.to_leptonica()) },
+            PixEdgeError::NullPtr,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 9 by 9 image ramping from 0 to 200 across the middle column
+    fn ramp_image() -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(9, 9, 8).unwrap();
+        for y in 0..9 {
+            pix.set_pixel(4, y, 100);
+            for x in 5..9 {
+                pix.set_pixel(x, y, 200);
+            }
+        }
+        pix
+    }
+
+    #[test]
+    fn sobel_edges_test() {
+        let pix = ramp_image();
+        let vertical = pix.sobel_edges(EdgeOrientation::Vertical).unwrap();
+        assert!(vertical.get_pixel(4, 4).unwrap() > 0);
+        assert_eq!(vertical.get_pixel(1, 4), Some(0));
+        let horizontal = pix.sobel_edges(EdgeOrientation::Horizontal).unwrap();
+        assert_eq!(horizontal.get_pixel(4, 4), Some(0));
+        let all = pix.sobel_edges(EdgeOrientation::All).unwrap();
+        assert!(all.get_pixel(4, 4).unwrap() > 0);
+    }
+
+    #[test]
+    fn two_sided_edges_test() {
+        let pix = ramp_image();
+        let vertical = pix.two_sided_edges(EdgeOrientation::Vertical).unwrap();
+        assert_eq!(vertical.get_pixel(4, 4), Some(100));
+        assert_eq!(vertical.get_pixel(2, 4), Some(0));
+        assert_eq!(
+            pix.two_sided_edges(EdgeOrientation::All).err(),
+            Some(PixEdgeError::UnsupportedOrientation(EdgeOrientation::All))
+        );
+    }
+
+    #[test]
+    fn unsupported_depth_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        assert_eq!(
+            pix.sobel_edges(EdgeOrientation::All).err(),
+            Some(PixEdgeError::UnsupportedDepth(32))
+        );
+    }
+}
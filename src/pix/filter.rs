@@ -0,0 +1,230 @@
+use super::Pix;
+use crate::memory::RefCountedExclusive;
+
+use leptonica_sys::{
+    l_float32, l_int32, pixBilateral, pixBlockBilateralExact, pixDestroy, pixMedianFilter,
+    pixMinMaxTiles, pixRankFilter,
+};
+use thiserror::Error;
+
+/// Error returned by the Pix filter methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixFilterError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix with a colormap is not supported")]
+    Colormapped,
+    #[error("Pix filter returned null")]
+    NullPtr,
+}
+
+impl Pix {
+    /// Leptonica's filters take gray or RGB pixel values, not colormap indices
+    fn check_filter_input(&self, depths: &[l_int32]) -> Result<(), PixFilterError> {
+        if self.get_colormap().is_some() {
+            return Err(PixFilterError::Colormapped);
+        }
+        self.check_depth(depths, PixFilterError::UnsupportedDepth)
+    }
+
+    /// Wrapper for [`pixRankFilter`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/rank.c)
+    ///
+    /// Input: 8 or 32 bpp without a colormap, wf and hf (filter size),
+    /// rank (0.0 for min, 1.0 for max)
+    pub fn rank_filter(
+        &self,
+        wf: l_int32,
+        hf: l_int32,
+        rank: l_float32,
+    ) -> Result<RefCountedExclusive<Pix>, PixFilterError> {
+        self.check_filter_input(&[8, 32])?;
+        Self::from_result(
+            unsafe { pixRankFilter(self.0, wf, hf, rank) },
+            PixFilterError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixMedianFilter`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/rank.c)
+    ///
+    /// Input: 8 or 32 bpp without a colormap, wf and hf (filter size)
+    pub fn median_filter(
+        &self,
+        wf: l_int32,
+        hf: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixFilterError> {
+        self.check_filter_input(&[8, 32])?;
+        Self::from_result(
+            unsafe { pixMedianFilter(self.0, wf, hf) },
+            PixFilterError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixBilateral`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/bilateral.c)
+    ///
+    /// Fast separable approximation of the bilateral filter.
+    /// Input: 8 or 32 bpp without a colormap, spatial_stdev (> 0.5), range_stdev (> 5.0),
+    /// ncomps (4 to 30), reduction (1, 2 or 4)
+    pub fn bilateral(
+        &self,
+        spatial_stdev: l_float32,
+        range_stdev: l_float32,
+        ncomps: l_int32,
+        reduction: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixFilterError> {
+        self.check_filter_input(&[8, 32])?;
+        Self::from_result(
+            unsafe { pixBilateral(self.0, spatial_stdev, range_stdev, ncomps, reduction) },
+            PixFilterError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixBlockBilateralExact`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/bilateral.c)
+    ///
+    /// Input: 8 or 32 bpp without a colormap, spatial_stdev (> 0.0), range_stdev (> 0.0)
+    pub fn block_bilateral_exact(
+        &self,
+        spatial_stdev: l_float32,
+        range_stdev: l_float32,
+    ) -> Result<RefCountedExclusive<Pix>, PixFilterError> {
+        self.check_filter_input(&[8, 32])?;
+        Self::from_result(
+            unsafe { pixBlockBilateralExact(self.0, spatial_stdev, range_stdev) },
+            PixFilterError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixMinMaxTiles`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/adaptmap.c)
+    ///
+    /// Input: 8 bpp without a colormap, sx and sy (tile size, at least 5), mindiff (minimum
+    /// difference to accept a tile), smoothx and smoothy (half-width of the smoothing filter,
+    /// 0 for none). Returns the tiled minimum and maximum images.
+    pub fn min_max_tiles(
+        &self,
+        sx: l_int32,
+        sy: l_int32,
+        mindiff: l_int32,
+        smoothx: l_int32,
+        smoothy: l_int32,
+    ) -> Result<(RefCountedExclusive<Pix>, RefCountedExclusive<Pix>), PixFilterError> {
+        self.check_filter_input(&[8])?;
+        let mut pixmin = std::ptr::null_mut();
+        let mut pixmax = std::ptr::null_mut();
+        let result = unsafe {
+            pixMinMaxTiles(
+                self.0,
+                sx,
+                sy,
+                mindiff,
+                smoothx,
+                smoothy,
+                &mut pixmin,
+                &mut pixmax,
+            )
+        };
+        if result != 0 || pixmin.is_null() || pixmax.is_null() {
+            unsafe {
+                pixDestroy(&mut pixmin);
+                pixDestroy(&mut pixmax);
+            }
+            Err(PixFilterError::NullPtr)
+        } else {
+            Ok(unsafe {
+                (
+                    RefCountedExclusive::new(Pix(pixmin)),
+                    RefCountedExclusive::new(Pix(pixmax)),
+                )
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptonica_sys::{pixConvertRGBToColormap, pixConvertTo1, pixConvertTo8};
+
+    fn color_image() -> RefCountedExclusive<Pix> {
+        Pix::read_mem(include_bytes!("../../image.png")).unwrap()
+    }
+
+    fn gray_image() -> RefCountedExclusive<Pix> {
+        unsafe { RefCountedExclusive::new(Pix(pixConvertTo8(color_image().0, 0))) }
+    }
+
+    #[test]
+    fn rank_filter_test() {
+        for pix in [gray_image(), color_image()].iter() {
+            let filtered = pix.rank_filter(3, 3, 0.2).unwrap();
+            assert_eq!(filtered.get_width(), 200);
+            assert_eq!(filtered.get_depth(), pix.get_depth());
+        }
+    }
+
+    #[test]
+    fn median_filter_test() {
+        for pix in [gray_image(), color_image()].iter() {
+            let filtered = pix.median_filter(3, 3).unwrap();
+            assert_eq!(filtered.get_height(), 23);
+            assert_eq!(filtered.get_depth(), pix.get_depth());
+        }
+    }
+
+    #[test]
+    fn bilateral_test() {
+        for pix in [gray_image(), color_image()].iter() {
+            let filtered = pix.bilateral(5.0, 50.0, 10, 1).unwrap();
+            assert_eq!(filtered.get_width(), 200);
+            assert_eq!(filtered.get_depth(), pix.get_depth());
+            let filtered = pix.block_bilateral_exact(3.0, 30.0).unwrap();
+            assert_eq!(filtered.get_width(), 200);
+            assert_eq!(filtered.get_depth(), pix.get_depth());
+        }
+    }
+
+    #[test]
+    fn min_max_tiles_test() {
+        let (pixmin, pixmax) = gray_image().min_max_tiles(5, 5, 0, 0, 0).unwrap();
+        assert_eq!(pixmin.get_width(), 40);
+        assert_eq!(pixmax.get_width(), 40);
+        assert_eq!(
+            color_image().min_max_tiles(5, 5, 0, 0, 0).err(),
+            Some(PixFilterError::UnsupportedDepth(32))
+        );
+    }
+
+    #[test]
+    fn unsupported_depth_test() {
+        let pix = unsafe { RefCountedExclusive::new(Pix(pixConvertTo1(gray_image().0, 128))) };
+        assert_eq!(
+            pix.median_filter(3, 3).err(),
+            Some(PixFilterError::UnsupportedDepth(1))
+        );
+        assert_eq!(
+            pix.bilateral(5.0, 50.0, 10, 1).err(),
+            Some(PixFilterError::UnsupportedDepth(1))
+        );
+    }
+
+    #[test]
+    fn colormapped_test() {
+        let pix =
+            unsafe { RefCountedExclusive::new(Pix(pixConvertRGBToColormap(color_image().0, 0))) };
+        assert!(pix.get_colormap().is_some());
+        assert_eq!(
+            pix.rank_filter(3, 3, 0.2).err(),
+            Some(PixFilterError::Colormapped)
+        );
+        assert_eq!(
+            pix.median_filter(3, 3).err(),
+            Some(PixFilterError::Colormapped)
+        );
+        assert_eq!(
+            pix.bilateral(5.0, 50.0, 10, 1).err(),
+            Some(PixFilterError::Colormapped)
+        );
+        assert_eq!(
+            pix.block_bilateral_exact(3.0, 30.0).err(),
+            Some(PixFilterError::Colormapped)
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use super::{Connectivity, Pix};
+use crate::memory::RefCountedExclusive;
+
+use leptonica_sys::{
+    l_int32, pixCloseGray, pixDilateGray, pixErodeGray, pixHDome, pixMorphGradient, pixOpenGray,
+    pixTophat, L_TOPHAT_BLACK, L_TOPHAT_WHITE,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix grayscale morphology methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixGrayMorphError {
+    #[error("Pix depth {0} is not supported, expected 8")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix grayscale morphology returned null")]
+    NullPtr,
+}
+
+/// Type of top-hat transform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tophat {
+    /// `L_TOPHAT_WHITE`: image minus its opening, keeps small light features
+    White,
+    /// `L_TOPHAT_BLACK`: closing minus the image, keeps small dark features
+    Black,
+}
+
+impl Pix {
+    /// Wrapper for [`pixErodeGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graymorph.c)
+    ///
+    /// Input: 8 bpp, hsize and vsize (odd brick sizes)
+    pub fn erode_gray(
+        &self,
+        hsize: l_int32,
+        vsize: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixErodeGray(self.0, hsize, vsize) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixDilateGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graymorph.c)
+    ///
+    /// Input: 8 bpp, hsize and vsize (odd brick sizes)
+    pub fn dilate_gray(
+        &self,
+        hsize: l_int32,
+        vsize: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixDilateGray(self.0, hsize, vsize) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixOpenGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graymorph.c)
+    ///
+    /// Input: 8 bpp, hsize and vsize (odd brick sizes)
+    pub fn open_gray(
+        &self,
+        hsize: l_int32,
+        vsize: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixOpenGray(self.0, hsize, vsize) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixCloseGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graymorph.c)
+    ///
+    /// Input: 8 bpp, hsize and vsize (odd brick sizes)
+    pub fn close_gray(
+        &self,
+        hsize: l_int32,
+        vsize: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixCloseGray(self.0, hsize, vsize) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixTophat`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/morphapp.c)
+    ///
+    /// Input: 8 bpp, hsize and vsize (odd brick sizes)
+    pub fn tophat(
+        &self,
+        hsize: l_int32,
+        vsize: l_int32,
+        r#type: Tophat,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        let r#type = match r#type {
+            Tophat::White => L_TOPHAT_WHITE,
+            Tophat::Black => L_TOPHAT_BLACK,
+        };
+        Self::from_result(
+            unsafe { pixTophat(self.0, hsize, vsize, r#type.try_into().unwrap()) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixHDome`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/morphapp.c)
+    ///
+    /// Input: 8 bpp, height (of the seed below the image).
+    /// Returns the peaks of the image, cut off `height` below their maxima.
+    pub fn h_dome(
+        &self,
+        height: l_int32,
+        connectivity: Connectivity,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixHDome(self.0, height, connectivity.to_leptonica()) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixMorphGradient`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/morphapp.c)
+    ///
+    /// Input: 8 bpp, hsize and vsize (odd brick sizes), smoothing (half-width of the
+    /// smoothing filter, 0 for none). Returns the dilation minus the erosion.
+    pub fn morph_gradient(
+        &self,
+        hsize: l_int32,
+        vsize: l_int32,
+        smoothing: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixGrayMorphError> {
+        self.check_depth(&[8], PixGrayMorphError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixMorphGradient(self.0, hsize, vsize, smoothing) },
+            PixGrayMorphError::NullPtr,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 9 by 9 image of `background` with a single pixel of `dot` in the center
+    fn dot_image(background: u32, dot: u32) -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(9, 9, 8).unwrap();
+        for y in 0..9 {
+            for x in 0..9 {
+                pix.set_pixel(x, y, background);
+            }
+        }
+        pix.set_pixel(4, 4, dot);
+        pix
+    }
+
+    #[test]
+    fn erode_dilate_test() {
+        let pix = dot_image(0, 200);
+        let dilated = pix.dilate_gray(3, 3).unwrap();
+        assert_eq!(dilated.get_pixel(3, 5), Some(200));
+        assert_eq!(dilated.get_pixel(2, 2), Some(0));
+        let eroded = pix.erode_gray(3, 3).unwrap();
+        assert_eq!(eroded.get_pixel(4, 4), Some(0));
+    }
+
+    #[test]
+    fn open_close_test() {
+        let opened = dot_image(0, 200).open_gray(3, 3).unwrap();
+        assert_eq!(opened.get_pixel(4, 4), Some(0));
+        let closed = dot_image(200, 0).close_gray(3, 3).unwrap();
+        assert_eq!(closed.get_pixel(4, 4), Some(200));
+    }
+
+    #[test]
+    fn tophat_test() {
+        let white = dot_image(0, 200).tophat(3, 3, Tophat::White).unwrap();
+        assert_eq!(white.get_pixel(4, 4), Some(200));
+        assert_eq!(white.get_pixel(3, 3), Some(0));
+        let black = dot_image(200, 0).tophat(3, 3, Tophat::Black).unwrap();
+        assert_eq!(black.get_pixel(4, 4), Some(200));
+        assert_eq!(black.get_pixel(3, 3), Some(0));
+    }
+
+    #[test]
+    fn h_dome_test() {
+        let dome = dot_image(0, 200).h_dome(50, Connectivity::Four).unwrap();
+        assert_eq!(dome.get_pixel(4, 4), Some(50));
+        assert_eq!(dome.get_pixel(0, 0), Some(0));
+    }
+
+    #[test]
+    fn morph_gradient_test() {
+        let gradient = dot_image(0, 200).morph_gradient(3, 3, 0).unwrap();
+        assert_eq!(gradient.get_pixel(5, 5), Some(200));
+        assert_eq!(gradient.get_pixel(0, 0), Some(0));
+    }
+
+    #[test]
+    fn unsupported_depth_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        assert_eq!(
+            pix.erode_gray(3, 3).err(),
+            Some(PixGrayMorphError::UnsupportedDepth(32))
+        );
+        assert_eq!(
+            pix.tophat(3, 3, Tophat::White).err(),
+            Some(PixGrayMorphError::UnsupportedDepth(32))
+        );
+    }
+}
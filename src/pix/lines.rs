@@ -0,0 +1,189 @@
+use super::{Connectivity, Pix};
+use crate::{memory::RefCountedExclusive, Pta};
+
+use leptonica_sys::{boxaGetBoxGeometry, l_float32, l_int32, pixCloseBrick, pixOpenBrick};
+use thiserror::Error;
+
+/// Error returned by the Pix line removal and detection methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixLinesError {
+    #[error("Pix depth {0} is not supported, expected 1")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix line extraction failed")]
+    Failed,
+}
+
+/// Orientation of the lines to remove or detect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineOrientation {
+    Horizontal,
+    Vertical,
+}
+
+impl LineOrientation {
+    /// Brick size (width, height) `along` the line and `across` it
+    fn brick(self, along: l_int32, across: l_int32) -> (l_int32, l_int32) {
+        match self {
+            Self::Horizontal => (along, across),
+            Self::Vertical => (across, along),
+        }
+    }
+}
+
+impl Pix {
+    /// Removes straight horizontal or vertical lines, such as the rules of a form
+    ///
+    /// Input: 1 bpp, min_length (shortest run of foreground pixels considered a line).
+    /// Lines are extracted with an opening by a `min_length` long brick (`pixOpenBrick`) and
+    /// subtracted (`pixSubtract`). Text strokes crossing a removed line are then bridged by
+    /// a closing across the line, sized from the thickest line found (`pixCloseBrick`), and
+    /// reconstructed from the remaining strokes within the original foreground
+    /// (`pixSeedfillBinary`).
+    pub fn remove_lines(
+        &self,
+        orientation: LineOrientation,
+        min_length: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixLinesError> {
+        let lines = self.extract_lines(orientation, min_length)?;
+        let removed = self.subtract(&lines).map_err(|_| PixLinesError::Failed)?;
+        let thickness = line_boxes(&lines)?
+            .into_iter()
+            .map(|(_, _, w, h)| match orientation {
+                LineOrientation::Horizontal => h,
+                LineOrientation::Vertical => w,
+            })
+            .max()
+            .unwrap_or(0);
+        if thickness == 0 {
+            return Ok(removed);
+        }
+        let (width, height) = orientation.brick(1, thickness + 2);
+        let bridged = Self::from_result(
+            unsafe { pixCloseBrick(std::ptr::null_mut(), removed.0, width, height) },
+            PixLinesError::Failed,
+        )?;
+        let mask = bridged.and(self).map_err(|_| PixLinesError::Failed)?;
+        removed
+            .seedfill_binary(&mask, Connectivity::Eight)
+            .map_err(|_| PixLinesError::Failed)
+    }
+
+    /// Finds straight horizontal or vertical lines, such as the rules of a form
+    ///
+    /// Input: 1 bpp, min_length (shortest run of foreground pixels considered a line).
+    /// Returns the two end points of each line, along its center, one line after another.
+    pub fn find_straight_lines(
+        &self,
+        orientation: LineOrientation,
+        min_length: l_int32,
+    ) -> Result<RefCountedExclusive<Pta>, PixLinesError> {
+        let lines = self.extract_lines(orientation, min_length)?;
+        let boxes = line_boxes(&lines)?;
+        let mut pta = Pta::create(2 * boxes.len() as l_int32).ok_or(PixLinesError::Failed)?;
+        for (x, y, w, h) in boxes {
+            let ((x1, y1), (x2, y2)) = match orientation {
+                LineOrientation::Horizontal => ((x, y + h / 2), (x + w - 1, y + h / 2)),
+                LineOrientation::Vertical => ((x + w / 2, y), (x + w / 2, y + h - 1)),
+            };
+            pta.add_pt(x1 as l_float32, y1 as l_float32);
+            pta.add_pt(x2 as l_float32, y2 as l_float32);
+        }
+        Ok(pta)
+    }
+
+    fn extract_lines(
+        &self,
+        orientation: LineOrientation,
+        min_length: l_int32,
+    ) -> Result<RefCountedExclusive<Pix>, PixLinesError> {
+        self.check_depth(&[1], PixLinesError::UnsupportedDepth)?;
+        let (width, height) = orientation.brick(min_length, 1);
+        Self::from_result(
+            unsafe { pixOpenBrick(std::ptr::null_mut(), self.0, width, height) },
+            PixLinesError::Failed,
+        )
+    }
+}
+
+/// Geometry (x, y, w, h) of each 8-connected line in `lines`
+fn line_boxes(lines: &Pix) -> Result<Vec<(l_int32, l_int32, l_int32, l_int32)>, PixLinesError> {
+    let boxa = lines
+        .conn_comp(Connectivity::Eight)
+        .map_err(|_| PixLinesError::Failed)?;
+    let boxa_ptr = AsRef::<leptonica_sys::Boxa>::as_ref(&*boxa) as *const _ as *mut _;
+    (0..boxa.get_count())
+        .map(|i| {
+            let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+            if unsafe { boxaGetBoxGeometry(boxa_ptr, i, &mut x, &mut y, &mut w, &mut h) } != 0 {
+                Err(PixLinesError::Failed)
+            } else {
+                Ok((x, y, w, h))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 20 by 20 image with a horizontal line at y = 10 crossed by a vertical stroke at x = 5
+    fn form_image() -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(20, 20, 1).unwrap();
+        for x in 0..20 {
+            pix.set_pixel(x, 10, 1);
+        }
+        for y in 5..16 {
+            pix.set_pixel(5, y, 1);
+        }
+        pix
+    }
+
+    #[test]
+    fn remove_lines_test() {
+        let pix = form_image();
+        let cleaned = pix.remove_lines(LineOrientation::Horizontal, 15).unwrap();
+        assert_eq!(cleaned.get_pixel(15, 10), Some(0));
+        assert_eq!(cleaned.get_pixel(5, 7), Some(1));
+        assert_eq!(cleaned.get_pixel(5, 10), Some(1));
+
+        // The stroke is too short to be a vertical line
+        let cleaned = pix.remove_lines(LineOrientation::Vertical, 15).unwrap();
+        assert!(*cleaned == *pix);
+    }
+
+    #[test]
+    fn remove_thick_lines_test() {
+        // 5 pixel thick vertical line at x = 8 to 12, crossed by a horizontal stroke at y = 5
+        let mut pix = Pix::create(20, 20, 1).unwrap();
+        for y in 0..20 {
+            for x in 8..13 {
+                pix.set_pixel(x, y, 1);
+            }
+        }
+        for x in 3..18 {
+            pix.set_pixel(x, 5, 1);
+        }
+        let cleaned = pix.remove_lines(LineOrientation::Vertical, 15).unwrap();
+        assert_eq!(cleaned.get_pixel(10, 15), Some(0));
+        assert!((3..18).all(|x| cleaned.get_pixel(x, 5) == Some(1)));
+    }
+
+    #[test]
+    fn find_straight_lines_test() {
+        let pix = form_image();
+        let pta = pix
+            .find_straight_lines(LineOrientation::Horizontal, 15)
+            .unwrap();
+        assert_eq!(pta.get_count(), 2);
+        assert_eq!(pta.get_ipt(0), Some((0, 10)));
+        assert_eq!(pta.get_ipt(1), Some((19, 10)));
+
+        let pta = pix
+            .find_straight_lines(LineOrientation::Vertical, 8)
+            .unwrap();
+        assert_eq!(pta.get_count(), 2);
+        assert_eq!(pta.get_ipt(0), Some((5, 5)));
+        assert_eq!(pta.get_ipt(1), Some((5, 15)));
+    }
+}
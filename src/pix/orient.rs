@@ -0,0 +1,195 @@
+use super::Pix;
+use crate::memory::RefCountedExclusive;
+
+use leptonica_sys::{
+    l_float32, l_int32, makeOrientDecision, pixConvertTo1, pixCopy, pixMirrorDetect,
+    pixOrientDetect, pixRotateOrth, pixUpDownDetect, L_TEXT_ORIENT_DOWN, L_TEXT_ORIENT_LEFT,
+    L_TEXT_ORIENT_RIGHT, L_TEXT_ORIENT_UP,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix orientation methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixOrientError {
+    #[error("Pix orientation detection failed")]
+    Failed,
+    #[error("Pix orientation returned null")]
+    NullPtr,
+}
+
+/// Orientation of the text on a page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// `L_TEXT_ORIENT_UNKNOWN`: no decision could be made with enough confidence
+    Unknown,
+    /// `L_TEXT_ORIENT_UP`: upright
+    Up,
+    /// `L_TEXT_ORIENT_LEFT`: rotated 90 degrees counter-clockwise
+    Left,
+    /// `L_TEXT_ORIENT_DOWN`: upside down
+    Down,
+    /// `L_TEXT_ORIENT_RIGHT`: rotated 90 degrees clockwise
+    Right,
+}
+
+impl Orientation {
+    fn from_leptonica(orient: l_int32) -> Self {
+        match orient.try_into() {
+            Ok(L_TEXT_ORIENT_UP) => Self::Up,
+            Ok(L_TEXT_ORIENT_LEFT) => Self::Left,
+            Ok(L_TEXT_ORIENT_DOWN) => Self::Down,
+            Ok(L_TEXT_ORIENT_RIGHT) => Self::Right,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Number of clockwise quarter turns that make the text upright
+    fn correction_quads(self) -> l_int32 {
+        match self {
+            Self::Unknown | Self::Up => 0,
+            Self::Left => 1,
+            Self::Down => 2,
+            Self::Right => 3,
+        }
+    }
+
+    /// Wrapper for [`makeOrientDecision`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/flipdetect.c)
+    ///
+    /// Uses the default minimum up confidence and ratio.
+    fn decide(
+        up_confidence: l_float32,
+        left_confidence: l_float32,
+    ) -> Result<Self, PixOrientError> {
+        let mut orient = 0;
+        if unsafe { makeOrientDecision(up_confidence, left_confidence, 0.0, 0.0, &mut orient, 0) }
+            != 0
+        {
+            Err(PixOrientError::Failed)
+        } else {
+            Ok(Self::from_leptonica(orient))
+        }
+    }
+}
+
+/// Result of [`Pix::detect_orientation`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientationEstimate {
+    /// The decided orientation of the text
+    pub rotation: Orientation,
+    /// Confidence that the text is up rather than down; negative for down
+    pub up_confidence: l_float32,
+    /// Confidence that the text is left rather than right; negative for right
+    pub left_confidence: l_float32,
+}
+
+impl Pix {
+    /// Wrapper for [`pixOrientDetect`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/flipdetect.c)
+    /// and [`makeOrientDecision`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/flipdetect.c)
+    ///
+    /// Input: text, best at 150 to 300 ppi. Other depths than 1 bpp are thresholded first.
+    pub fn detect_orientation(&self) -> Result<OrientationEstimate, PixOrientError> {
+        let (mut up_confidence, mut left_confidence) = (0.0, 0.0);
+        let result = self.with_binary_for_orient(|pix| unsafe {
+            pixOrientDetect(pix.0, &mut up_confidence, &mut left_confidence, 0, 0)
+        })?;
+        if result != 0 {
+            return Err(PixOrientError::Failed);
+        }
+        Ok(OrientationEstimate {
+            rotation: Orientation::decide(up_confidence, left_confidence)?,
+            up_confidence,
+            left_confidence,
+        })
+    }
+
+    /// Wrapper for [`pixUpDownDetect`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/flipdetect.c)
+    ///
+    /// Input: text, best at 150 to 300 ppi. Other depths than 1 bpp are thresholded first.
+    /// Returns the confidence that the text is up rather than down; negative for down.
+    pub fn detect_up_down(&self) -> Result<l_float32, PixOrientError> {
+        let mut confidence = 0.0;
+        let result = self.with_binary_for_orient(|pix| unsafe {
+            pixUpDownDetect(pix.0, &mut confidence, 0, 0, 0)
+        })?;
+        if result != 0 {
+            Err(PixOrientError::Failed)
+        } else {
+            Ok(confidence)
+        }
+    }
+
+    /// Wrapper for [`pixMirrorDetect`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/flipdetect.c)
+    ///
+    /// Input: upright text, best at 150 to 300 ppi. Other depths than 1 bpp are thresholded
+    /// first. Returns the confidence that the text is not mirrored; negative for mirrored.
+    pub fn detect_mirror(&self) -> Result<l_float32, PixOrientError> {
+        let mut confidence = 0.0;
+        let result = self.with_binary_for_orient(|pix| unsafe {
+            pixMirrorDetect(pix.0, &mut confidence, 0, 0)
+        })?;
+        if result != 0 {
+            Err(PixOrientError::Failed)
+        } else {
+            Ok(confidence)
+        }
+    }
+
+    /// Rotates the image so its text is upright, see [`Pix::detect_orientation`]
+    ///
+    /// Returns a copy if the orientation is up or unknown.
+    pub fn auto_orient(&self) -> Result<RefCountedExclusive<Pix>, PixOrientError> {
+        let quads = self.detect_orientation()?.rotation.correction_quads();
+        let ptr = unsafe {
+            if quads == 0 {
+                pixCopy(std::ptr::null_mut(), self.0)
+            } else {
+                pixRotateOrth(self.0, quads)
+            }
+        };
+        Self::from_result(ptr, PixOrientError::NullPtr)
+    }
+
+    /// Runs `f` on the image if it is 1 bpp, otherwise on a thresholded copy
+    fn with_binary_for_orient<T>(&self, f: impl FnOnce(&Pix) -> T) -> Result<T, PixOrientError> {
+        if self.get_depth() == 1 {
+            return Ok(f(self));
+        }
+        let binary = Self::from_result(
+            unsafe { pixConvertTo1(self.0, 130) },
+            PixOrientError::NullPtr,
+        )?;
+        Ok(f(&binary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decide_test() {
+        assert_eq!(Orientation::decide(20.0, 0.0), Ok(Orientation::Up));
+        assert_eq!(Orientation::decide(-20.0, 0.0), Ok(Orientation::Down));
+        assert_eq!(Orientation::decide(0.0, 20.0), Ok(Orientation::Left));
+        assert_eq!(Orientation::decide(0.0, -20.0), Ok(Orientation::Right));
+        assert_eq!(Orientation::decide(0.1, 0.1), Ok(Orientation::Unknown));
+    }
+
+    #[test]
+    fn detect_orientation_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let estimate = pix.detect_orientation().unwrap();
+        assert!(estimate.up_confidence.is_finite());
+        assert!(estimate.left_confidence.is_finite());
+        assert!(pix.detect_up_down().unwrap().is_finite());
+        assert!(pix.detect_mirror().unwrap().is_finite());
+
+        let oriented = pix.auto_orient().unwrap();
+        assert_eq!(oriented.get_depth(), 32);
+        assert_eq!(
+            oriented.get_width() * oriented.get_height(),
+            pix.get_width() * pix.get_height()
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use super::Pix;
+use crate::{
+    memory::{BorrowedFrom, RefCountedExclusive},
+    PixColormap,
+};
+
+use leptonica_sys::{
+    l_float32, l_int32, pixColorFraction, pixColorsForQuantization, pixDestroy,
+    pixFixedOctcubeQuant256, pixGetColormap, pixMedianCutQuant, pixNumColors, pixOctreeColorQuant,
+};
+use thiserror::Error;
+
+/// Error returned by the Pix color quantization and analysis methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixQuantizeError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix color analysis failed")]
+    Failed,
+    #[error("Pix color quantization returned null")]
+    NullPtr,
+}
+
+/// A colormapped Pix produced by color quantization
+#[derive(Debug)]
+pub struct Quantized {
+    pix: RefCountedExclusive<Pix>,
+}
+
+impl Quantized {
+    /// The quantized image
+    pub fn pix(&self) -> &Pix {
+        &self.pix
+    }
+
+    /// The colormap of the quantized image
+    pub fn colormap(&self) -> BorrowedFrom<'_, PixColormap> {
+        self.pix.get_colormap().unwrap()
+    }
+
+    /// Take the quantized image, keeping its colormap
+    pub fn into_pix(self) -> RefCountedExclusive<Pix> {
+        self.pix
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixMedianCutQuant`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorquant2.c)
+    ///
+    /// Input: 32 bpp. Quantizes to at most 256 colors, optionally with Floyd-Steinberg dithering.
+    pub fn median_cut_quant(&self, dither: bool) -> Result<Quantized, PixQuantizeError> {
+        self.check_depth(&[32], PixQuantizeError::UnsupportedDepth)?;
+        Self::from_quantize(unsafe { pixMedianCutQuant(self.0, dither.into()) })
+    }
+
+    /// Wrapper for [`pixOctreeColorQuant`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorquant1.c)
+    ///
+    /// Input: 32 bpp, colors (number of colors in the colormap, 128 to 240).
+    pub fn octree_color_quant(
+        &self,
+        colors: l_int32,
+        dither: bool,
+    ) -> Result<Quantized, PixQuantizeError> {
+        self.check_depth(&[32], PixQuantizeError::UnsupportedDepth)?;
+        Self::from_quantize(unsafe { pixOctreeColorQuant(self.0, colors, dither.into()) })
+    }
+
+    /// Wrapper for [`pixFixedOctcubeQuant256`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorquant1.c)
+    ///
+    /// Input: 32 bpp. Quantizes to a fixed colormap of 256 colors.
+    pub fn fixed_octcube_quant_256(&self, dither: bool) -> Result<Quantized, PixQuantizeError> {
+        self.check_depth(&[32], PixQuantizeError::UnsupportedDepth)?;
+        Self::from_quantize(unsafe { pixFixedOctcubeQuant256(self.0, dither.into()) })
+    }
+
+    /// Wrapper for [`pixColorsForQuantization`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorcontent.c)
+    ///
+    /// Input: 8 or 32 bpp, or colormapped, thresh (edge threshold, 0 for the default of 15).
+    /// Returns an estimate of the number of colors needed for quantization and whether
+    /// the image has significant color.
+    pub fn colors_for_quantization(
+        &self,
+        thresh: l_int32,
+    ) -> Result<(l_int32, bool), PixQuantizeError> {
+        if self.get_colormap().is_none() {
+            self.check_depth(&[8, 32], PixQuantizeError::UnsupportedDepth)?;
+        }
+        let (mut ncolors, mut iscolor) = (0, 0);
+        let result =
+            unsafe { pixColorsForQuantization(self.0, thresh, &mut ncolors, &mut iscolor, 0) };
+        if result != 0 {
+            Err(PixQuantizeError::Failed)
+        } else {
+            Ok((ncolors, iscolor != 0))
+        }
+    }
+
+    /// Wrapper for [`pixColorFraction`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorcontent.c)
+    ///
+    /// Input: 32 bpp, darkthresh and lightthresh (pixels with all components below or above are
+    /// ignored), diffthresh (smallest component difference of a colored pixel), factor
+    /// (sampling factor). Returns the fraction of pixels considered and the fraction of
+    /// those that are colored.
+    pub fn color_fraction(
+        &self,
+        darkthresh: l_int32,
+        lightthresh: l_int32,
+        diffthresh: l_int32,
+        factor: l_int32,
+    ) -> Result<(l_float32, l_float32), PixQuantizeError> {
+        self.check_depth(&[32], PixQuantizeError::UnsupportedDepth)?;
+        let (mut pixfract, mut colorfract) = (0.0, 0.0);
+        let result = unsafe {
+            pixColorFraction(
+                self.0,
+                darkthresh,
+                lightthresh,
+                diffthresh,
+                factor,
+                &mut pixfract,
+                &mut colorfract,
+            )
+        };
+        if result != 0 {
+            Err(PixQuantizeError::Failed)
+        } else {
+            Ok((pixfract, colorfract))
+        }
+    }
+
+    /// Wrapper for [`pixNumColors`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/colorcontent.c)
+    ///
+    /// Input: 2, 4, 8 or 32 bpp, factor (sampling factor).
+    /// For 32 bpp images with more than 256 colors, returns 0.
+    pub fn num_colors(&self, factor: l_int32) -> Result<l_int32, PixQuantizeError> {
+        self.check_depth(&[2, 4, 8, 32], PixQuantizeError::UnsupportedDepth)?;
+        let mut ncolors = 0;
+        if unsafe { pixNumColors(self.0, factor, &mut ncolors) } != 0 {
+            Err(PixQuantizeError::Failed)
+        } else {
+            Ok(ncolors)
+        }
+    }
+
+    fn from_quantize(mut ptr: *mut leptonica_sys::Pix) -> Result<Quantized, PixQuantizeError> {
+        if ptr.is_null() || unsafe { pixGetColormap(ptr) }.is_null() {
+            unsafe { pixDestroy(&mut ptr) };
+            Err(PixQuantizeError::NullPtr)
+        } else {
+            Ok(Quantized {
+                pix: unsafe { RefCountedExclusive::new(Pix(ptr)) },
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantize_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+
+        let quantized = pix.median_cut_quant(false).unwrap();
+        assert_eq!(quantized.pix().get_width(), 200);
+        let count = quantized.colormap().get_count();
+        assert!(count > 0 && count <= 256);
+        assert_eq!(quantized.colormap().get_colors().len() as l_int32, count);
+
+        let quantized = pix.octree_color_quant(128, true).unwrap();
+        assert!(quantized.colormap().get_count() <= 240);
+
+        let quantized = pix.fixed_octcube_quant_256(false).unwrap();
+        assert_eq!(quantized.colormap().get_count(), 256);
+        let quantized = quantized.into_pix();
+        assert_eq!(quantized.get_depth(), 8);
+        assert_eq!(
+            quantized.median_cut_quant(false).err(),
+            Some(PixQuantizeError::UnsupportedDepth(8))
+        );
+    }
+
+    #[test]
+    fn color_content_test() {
+        let mut pix = Pix::create(4, 4, 32).unwrap();
+        assert_eq!(pix.num_colors(1), Ok(1));
+        assert!(!pix.colors_for_quantization(0).unwrap().1);
+        assert_eq!(pix.color_fraction(20, 244, 40, 1).unwrap().0, 0.0);
+
+        pix.set_pixel(0, 0, 0xff000000);
+        assert_eq!(pix.num_colors(1), Ok(2));
+        assert!(pix.get_colormap().is_none());
+        assert_eq!(
+            Pix::create(4, 4, 1)
+                .unwrap()
+                .colors_for_quantization(0)
+                .err(),
+            Some(PixQuantizeError::UnsupportedDepth(1))
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use super::Pix;
+use crate::{Box, Boxa, Pta};
+
+use leptonica_sys::{
+    l_float32, l_int32, l_uint32, pixRenderBox, pixRenderBoxArb, pixRenderBoxBlend, pixRenderBoxa,
+    pixRenderBoxaArb, pixRenderBoxaBlend, pixRenderLine, pixRenderLineArb, pixRenderLineBlend,
+    pixRenderPta, pixRenderPtaArb, pixRenderPtaBlend, L_CLEAR_PIXELS, L_FLIP_PIXELS, L_SET_PIXELS,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix render methods
+#[derive(Debug, Error, PartialEq)]
+#[error("Pix rendering failed")]
+pub struct PixRenderError();
+
+/// A color to render with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// The color as a 32 bpp pixel value, `0xrrggbb00`
+    pub fn to_pixel(self) -> l_uint32 {
+        (l_uint32::from(self.r) << 24)
+            | (l_uint32::from(self.g) << 16)
+            | (l_uint32::from(self.b) << 8)
+    }
+}
+
+/// How the pixels under a rendered shape are changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderOp {
+    /// `L_SET_PIXELS`
+    Set,
+    /// `L_CLEAR_PIXELS`
+    Clear,
+    /// `L_FLIP_PIXELS`
+    Flip,
+}
+
+impl RenderOp {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Set => L_SET_PIXELS,
+            Self::Clear => L_CLEAR_PIXELS,
+            Self::Flip => L_FLIP_PIXELS,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+fn box_ptr(r#box: &Box) -> *mut leptonica_sys::Box {
+    AsRef::<leptonica_sys::Box>::as_ref(r#box) as *const _ as *mut _
+}
+
+fn boxa_ptr(boxa: &Boxa) -> *mut leptonica_sys::Boxa {
+    AsRef::<leptonica_sys::Boxa>::as_ref(boxa) as *const _ as *mut _
+}
+
+fn check_render(result: l_int32) -> Result<(), PixRenderError> {
+    if result != 0 {
+        Err(PixRenderError())
+    } else {
+        Ok(())
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixRenderBox`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_box(
+        &mut self,
+        r#box: &Box,
+        width: l_int32,
+        op: RenderOp,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe { pixRenderBox(self.0, box_ptr(r#box), width, op.to_leptonica()) })
+    }
+
+    /// Wrapper for [`pixRenderBoxArb`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    ///
+    /// Uses the closest available color on images that are not 32 bpp.
+    pub fn render_box_arb(
+        &mut self,
+        r#box: &Box,
+        width: l_int32,
+        color: Rgb,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderBoxArb(self.0, box_ptr(r#box), width, color.r, color.g, color.b)
+        })
+    }
+
+    /// Wrapper for [`pixRenderBoxBlend`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    ///
+    /// Input: 32 bpp, fract (fraction of `color` to blend in, 0.0 to 1.0)
+    pub fn render_box_blend(
+        &mut self,
+        r#box: &Box,
+        width: l_int32,
+        color: Rgb,
+        fract: l_float32,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderBoxBlend(
+                self.0,
+                box_ptr(r#box),
+                width,
+                color.r,
+                color.g,
+                color.b,
+                fract,
+            )
+        })
+    }
+
+    /// Wrapper for [`pixRenderBoxa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_boxa(
+        &mut self,
+        boxa: &Boxa,
+        width: l_int32,
+        op: RenderOp,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe { pixRenderBoxa(self.0, boxa_ptr(boxa), width, op.to_leptonica()) })
+    }
+
+    /// Wrapper for [`pixRenderBoxaArb`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_boxa_arb(
+        &mut self,
+        boxa: &Boxa,
+        width: l_int32,
+        color: Rgb,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderBoxaArb(self.0, boxa_ptr(boxa), width, color.r, color.g, color.b)
+        })
+    }
+
+    /// Wrapper for [`pixRenderBoxaBlend`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    ///
+    /// Input: 32 bpp, fract (fraction of `color` to blend in, 0.0 to 1.0),
+    /// removedups (whether to only render identical boxes once)
+    pub fn render_boxa_blend(
+        &mut self,
+        boxa: &Boxa,
+        width: l_int32,
+        color: Rgb,
+        fract: l_float32,
+        removedups: bool,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderBoxaBlend(
+                self.0,
+                boxa_ptr(boxa),
+                width,
+                color.r,
+                color.g,
+                color.b,
+                fract,
+                removedups.into(),
+            )
+        })
+    }
+
+    /// Wrapper for [`pixRenderLine`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_line(
+        &mut self,
+        (x1, y1): (l_int32, l_int32),
+        (x2, y2): (l_int32, l_int32),
+        width: l_int32,
+        op: RenderOp,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe { pixRenderLine(self.0, x1, y1, x2, y2, width, op.to_leptonica()) })
+    }
+
+    /// Wrapper for [`pixRenderLineArb`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_line_arb(
+        &mut self,
+        (x1, y1): (l_int32, l_int32),
+        (x2, y2): (l_int32, l_int32),
+        width: l_int32,
+        color: Rgb,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderLineArb(self.0, x1, y1, x2, y2, width, color.r, color.g, color.b)
+        })
+    }
+
+    /// Wrapper for [`pixRenderLineBlend`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    ///
+    /// Input: 32 bpp, fract (fraction of `color` to blend in, 0.0 to 1.0)
+    pub fn render_line_blend(
+        &mut self,
+        (x1, y1): (l_int32, l_int32),
+        (x2, y2): (l_int32, l_int32),
+        width: l_int32,
+        color: Rgb,
+        fract: l_float32,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderLineBlend(
+                self.0, x1, y1, x2, y2, width, color.r, color.g, color.b, fract,
+            )
+        })
+    }
+
+    /// Wrapper for [`pixRenderPta`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_pta(&mut self, pta: &Pta, op: RenderOp) -> Result<(), PixRenderError> {
+        check_render(unsafe { pixRenderPta(self.0, *pta.as_ref(), op.to_leptonica()) })
+    }
+
+    /// Wrapper for [`pixRenderPtaArb`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    pub fn render_pta_arb(&mut self, pta: &Pta, color: Rgb) -> Result<(), PixRenderError> {
+        check_render(unsafe { pixRenderPtaArb(self.0, *pta.as_ref(), color.r, color.g, color.b) })
+    }
+
+    /// Wrapper for [`pixRenderPtaBlend`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/graphics.c)
+    ///
+    /// Input: 32 bpp, fract (fraction of `color` to blend in, 0.0 to 1.0)
+    pub fn render_pta_blend(
+        &mut self,
+        pta: &Pta,
+        color: Rgb,
+        fract: l_float32,
+    ) -> Result<(), PixRenderError> {
+        check_render(unsafe {
+            pixRenderPtaBlend(self.0, *pta.as_ref(), color.r, color.g, color.b, fract)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptonica_sys::{boxaAddBox, L_COPY};
+
+    #[test]
+    fn render_box_test() {
+        let mut pix = Pix::create(10, 10, 8).unwrap();
+        let r#box = Box::create_valid(2, 2, 5, 5).unwrap();
+        pix.render_box(&r#box, 1, RenderOp::Set).unwrap();
+        assert_eq!(pix.get_pixel(2, 2), Some(255));
+        assert_eq!(pix.get_pixel(6, 4), Some(255));
+        assert_eq!(pix.get_pixel(4, 4), Some(0));
+        pix.render_box(&r#box, 1, RenderOp::Flip).unwrap();
+        assert_eq!(pix.get_pixel(4, 2), Some(0));
+    }
+
+    #[test]
+    fn render_boxa_test() {
+        let mut boxa = Boxa::create(2).unwrap();
+        for r#box in [
+            Box::create_valid(0, 0, 3, 3).unwrap(),
+            Box::create_valid(5, 5, 3, 3).unwrap(),
+        ]
+        .iter_mut()
+        {
+            unsafe { boxaAddBox(boxa.as_mut(), r#box.as_mut(), L_COPY.try_into().unwrap()) };
+        }
+        let mut pix = Pix::create(10, 10, 32).unwrap();
+        pix.render_boxa_arb(&boxa, 1, Rgb::new(255, 0, 0)).unwrap();
+        assert_eq!(pix.get_pixel(0, 0), Some(Rgb::new(255, 0, 0).to_pixel()));
+        assert_eq!(pix.get_pixel(7, 7), Some(Rgb::new(255, 0, 0).to_pixel()));
+        assert_eq!(pix.get_pixel(1, 1), Some(0));
+
+        pix.render_boxa_blend(&boxa, 1, Rgb::new(0, 0, 255), 0.5, false)
+            .unwrap();
+        let blended = pix.get_pixel(5, 5).unwrap();
+        assert_ne!(blended, Rgb::new(255, 0, 0).to_pixel());
+        assert_ne!(blended, Rgb::new(0, 0, 255).to_pixel());
+    }
+
+    #[test]
+    fn render_line_test() {
+        let mut pix = Pix::create(10, 10, 1).unwrap();
+        pix.render_line((0, 9), (9, 9), 1, RenderOp::Set).unwrap();
+        assert_eq!(pix.get_pixel(5, 9), Some(1));
+        assert_eq!(pix.get_pixel(5, 8), Some(0));
+
+        let mut pix = Pix::create(10, 10, 32).unwrap();
+        let green = Rgb::new(0, 255, 0);
+        pix.render_line_arb((3, 0), (3, 9), 1, green).unwrap();
+        assert_eq!(pix.get_pixel(3, 5), Some(green.to_pixel()));
+    }
+
+    #[test]
+    fn render_pta_test() {
+        let mut pta = Pta::create(2).unwrap();
+        pta.add_pt(1.0, 1.0);
+        pta.add_pt(8.0, 3.0);
+        let mut pix = Pix::create(10, 10, 8).unwrap();
+        pix.render_pta(&pta, RenderOp::Set).unwrap();
+        assert_eq!(pix.get_pixel(1, 1), Some(255));
+        assert_eq!(pix.get_pixel(8, 3), Some(255));
+        assert_eq!(pix.get_pixel(2, 2), Some(0));
+    }
+
+    #[test]
+    fn rgb_test() {
+        assert_eq!(Rgb::new(0x12, 0x34, 0x56).to_pixel(), 0x12345600);
+    }
+}
@@ -0,0 +1,220 @@
+use super::{Connectivity, Pix};
+use crate::memory::RefCountedExclusive;
+
+use leptonica_sys::{
+    l_float32, l_int32, pixDistanceFunction, pixFillClosedBorders, pixFillHolesToBoundingRect,
+    pixHolesByFilling, pixSeedfillBinary, pixSeedfillGray, L_BOUNDARY_BG, L_BOUNDARY_FG,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix seedfill methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixSeedfillError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix seedfill failed")]
+    Failed,
+    #[error("Pix seedfill returned null")]
+    NullPtr,
+}
+
+/// How pixels outside of the image are treated by [`Pix::distance_function`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceBoundary {
+    /// `L_BOUNDARY_BG`: as background, so the distance is measured to the image edge too
+    Background,
+    /// `L_BOUNDARY_FG`: as foreground
+    Foreground,
+}
+
+impl DistanceBoundary {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Background => L_BOUNDARY_BG,
+            Self::Foreground => L_BOUNDARY_FG,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl Pix {
+    /// Wrapper for [`pixDistanceFunction`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/seedfill.c)
+    ///
+    /// Input: 1 bpp, outdepth (8 or 16). Each foreground pixel gets its distance to the
+    /// nearest background pixel; 8 bpp distances are clipped to 255.
+    pub fn distance_function(
+        &self,
+        connectivity: Connectivity,
+        outdepth: l_int32,
+        boundary: DistanceBoundary,
+    ) -> Result<RefCountedExclusive<Pix>, PixSeedfillError> {
+        self.check_depth(&[1], PixSeedfillError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe {
+                pixDistanceFunction(
+                    self.0,
+                    connectivity.to_leptonica(),
+                    outdepth,
+                    boundary.to_leptonica(),
+                )
+            },
+            PixSeedfillError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixSeedfillBinary`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/seedfill.c)
+    ///
+    /// Input: 1 bpp seed and mask. Returns the foreground of the mask reachable from the seed.
+    pub fn seedfill_binary(
+        &self,
+        mask: &Pix,
+        connectivity: Connectivity,
+    ) -> Result<RefCountedExclusive<Pix>, PixSeedfillError> {
+        self.check_depth(&[1], PixSeedfillError::UnsupportedDepth)?;
+        mask.check_depth(&[1], PixSeedfillError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe {
+                pixSeedfillBinary(
+                    std::ptr::null_mut(),
+                    self.0,
+                    mask.0,
+                    connectivity.to_leptonica(),
+                )
+            },
+            PixSeedfillError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixSeedfillGray`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/seedfill.c)
+    ///
+    /// Input: 8 bpp seed and mask of the same size. In place, the seed is grown into the
+    /// regions where the mask is at least as large, but never beyond the mask.
+    pub fn seedfill_gray(
+        &mut self,
+        mask: &Pix,
+        connectivity: Connectivity,
+    ) -> Result<(), PixSeedfillError> {
+        self.check_depth(&[8], PixSeedfillError::UnsupportedDepth)?;
+        mask.check_depth(&[8], PixSeedfillError::UnsupportedDepth)?;
+        if unsafe { pixSeedfillGray(self.0, mask.0, connectivity.to_leptonica()) } != 0 {
+            Err(PixSeedfillError::Failed)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`pixFillHolesToBoundingRect`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/seedfill.c)
+    ///
+    /// Input: 1 bpp, minsize (smallest component area considered), maxhfract (largest hole
+    /// area fraction of a component whose holes are filled), minfgfract (smallest foreground
+    /// fraction of the bounding rectangle for it to be filled completely).
+    pub fn fill_holes_to_bounding_rect(
+        &self,
+        minsize: l_int32,
+        maxhfract: l_float32,
+        minfgfract: l_float32,
+    ) -> Result<RefCountedExclusive<Pix>, PixSeedfillError> {
+        self.check_depth(&[1], PixSeedfillError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixFillHolesToBoundingRect(self.0, minsize, maxhfract, minfgfract) },
+            PixSeedfillError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixHolesByFilling`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/seedfill.c)
+    ///
+    /// Input: 1 bpp. Returns the holes as foreground.
+    pub fn holes_by_filling(
+        &self,
+        connectivity: Connectivity,
+    ) -> Result<RefCountedExclusive<Pix>, PixSeedfillError> {
+        self.check_depth(&[1], PixSeedfillError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixHolesByFilling(self.0, connectivity.to_leptonica()) },
+            PixSeedfillError::NullPtr,
+        )
+    }
+
+    /// Wrapper for [`pixFillClosedBorders`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/seedfill.c)
+    ///
+    /// Input: 1 bpp. Returns the image with its holes filled.
+    pub fn fill_closed_borders(
+        &self,
+        connectivity: Connectivity,
+    ) -> Result<RefCountedExclusive<Pix>, PixSeedfillError> {
+        self.check_depth(&[1], PixSeedfillError::UnsupportedDepth)?;
+        Self::from_result(
+            unsafe { pixFillClosedBorders(self.0, connectivity.to_leptonica()) },
+            PixSeedfillError::NullPtr,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ring_image;
+
+    #[test]
+    fn distance_function_test() {
+        let filled = ring_image()
+            .fill_closed_borders(Connectivity::Four)
+            .unwrap();
+        let distance = filled
+            .distance_function(Connectivity::Four, 8, DistanceBoundary::Background)
+            .unwrap();
+        assert_eq!(distance.get_depth(), 8);
+        assert_eq!(distance.get_pixel(3, 3), Some(3));
+        assert_eq!(distance.get_pixel(1, 1), Some(1));
+        assert_eq!(distance.get_pixel(0, 0), Some(0));
+    }
+
+    #[test]
+    fn seedfill_binary_test() {
+        let mut mask = ring_image();
+        mask.set_pixel(3, 3, 1);
+        let mut seed = Pix::create(7, 7, 1).unwrap();
+        seed.set_pixel(1, 1, 1);
+        let filled = seed.seedfill_binary(&mask, Connectivity::Four).unwrap();
+        assert_eq!(filled.get_pixel(5, 5), Some(1));
+        assert_eq!(filled.get_pixel(3, 3), Some(0));
+    }
+
+    #[test]
+    fn seedfill_gray_test() {
+        let mut mask = Pix::create(3, 3, 8).unwrap();
+        let mut seed = Pix::create(3, 3, 8).unwrap();
+        for y in 0..3 {
+            for x in 0..3 {
+                mask.set_pixel(x, y, 200);
+            }
+        }
+        seed.set_pixel(1, 1, 100);
+        seed.seedfill_gray(&mask, Connectivity::Eight).unwrap();
+        assert_eq!(seed.get_pixel(0, 0), Some(100));
+        assert_eq!(
+            seed.seedfill_gray(&ring_image(), Connectivity::Eight),
+            Err(PixSeedfillError::UnsupportedDepth(1))
+        );
+    }
+
+    #[test]
+    fn holes_test() {
+        let pix = ring_image();
+        let holes = pix.holes_by_filling(Connectivity::Four).unwrap();
+        assert_eq!(holes.get_pixel(3, 3), Some(1));
+        assert_eq!(holes.get_pixel(1, 1), Some(0));
+        assert_eq!(holes.get_pixel(0, 0), Some(0));
+
+        let filled = pix.fill_closed_borders(Connectivity::Four).unwrap();
+        assert_eq!(filled.get_pixel(3, 3), Some(1));
+        assert_eq!(filled.get_pixel(1, 1), Some(1));
+        assert_eq!(filled.get_pixel(0, 0), Some(0));
+
+        let filled = pix.fill_holes_to_bounding_rect(1, 1.0, 0.5).unwrap();
+        assert_eq!(filled.get_pixel(3, 3), Some(1));
+        assert_eq!(filled.get_pixel(0, 0), Some(0));
+    }
+}
@@ -0,0 +1,301 @@
+use super::Pix;
+use crate::{memory::RefCountedExclusive, Numa};
+
+use leptonica_sys::{
+    l_float32, l_int32, numaDestroy, pixColumnStats, pixCountPixels, pixForegroundFraction,
+    pixGetAverageMasked, pixGetRankValueMasked, pixRowStats, pixZero, L_MEAN_ABSVAL,
+    L_ROOT_MEAN_SQUARE, L_STANDARD_DEVIATION, L_VARIANCE,
+};
+use std::convert::TryInto;
+use thiserror::Error;
+
+/// Error returned by the Pix statistics methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixStatsError {
+    #[error("Pix depth {0} is not supported")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix statistics failed")]
+    Failed,
+}
+
+/// Statistic computed by [`Pix::average_masked`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatType {
+    /// `L_MEAN_ABSVAL`
+    MeanAbsVal,
+    /// `L_ROOT_MEAN_SQUARE`
+    RootMeanSquare,
+    /// `L_STANDARD_DEVIATION`
+    StandardDeviation,
+    /// `L_VARIANCE`
+    Variance,
+}
+
+impl StatType {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::MeanAbsVal => L_MEAN_ABSVAL,
+            Self::RootMeanSquare => L_ROOT_MEAN_SQUARE,
+            Self::StandardDeviation => L_STANDARD_DEVIATION,
+            Self::Variance => L_VARIANCE,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+/// Statistic computed for each row or column by [`Pix::row_stats`] and [`Pix::column_stats`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineStat {
+    Mean,
+    Median,
+    Mode,
+    /// Number of pixels with the mode value
+    ModeCount,
+    Variance,
+    /// Square root of the variance
+    RootVariance,
+}
+
+type LineStatsFn = unsafe extern "C" fn(
+    *mut leptonica_sys::Pix,
+    *mut leptonica_sys::Box,
+    *mut *mut leptonica_sys::Numa,
+    *mut *mut leptonica_sys::Numa,
+    *mut *mut leptonica_sys::Numa,
+    *mut *mut leptonica_sys::Numa,
+    *mut *mut leptonica_sys::Numa,
+    *mut *mut leptonica_sys::Numa,
+) -> l_int32;
+
+impl Pix {
+    /// Wrapper for [`pixCountPixels`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Input: 1 bpp. Returns the number of foreground pixels.
+    pub fn count_pixels(&self) -> Result<l_int32, PixStatsError> {
+        self.check_depth(&[1], PixStatsError::UnsupportedDepth)?;
+        let mut count = 0;
+        if unsafe { pixCountPixels(self.0, &mut count, std::ptr::null_mut()) } != 0 {
+            Err(PixStatsError::Failed)
+        } else {
+            Ok(count)
+        }
+    }
+
+    /// Wrapper for [`pixForegroundFraction`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Input: 1 bpp. Returns the fraction of pixels that are foreground.
+    pub fn foreground_fraction(&self) -> Result<l_float32, PixStatsError> {
+        self.check_depth(&[1], PixStatsError::UnsupportedDepth)?;
+        let mut fract = 0.0;
+        if unsafe { pixForegroundFraction(self.0, &mut fract) } != 0 {
+            Err(PixStatsError::Failed)
+        } else {
+            Ok(fract)
+        }
+    }
+
+    /// Wrapper for [`pixGetAverageMasked`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix4.c)
+    ///
+    /// Input: 8 or 16 bpp, mask (optional 1 bpp, aligned to the upper left corner; only pixels
+    /// under its foreground are used), factor (sampling factor, 1 for every pixel).
+    pub fn average_masked(
+        &self,
+        mask: Option<&Pix>,
+        factor: l_int32,
+        stat_type: StatType,
+    ) -> Result<l_float32, PixStatsError> {
+        self.check_depth(&[8, 16], PixStatsError::UnsupportedDepth)?;
+        let mask = Self::stats_mask(mask)?;
+        let mut val = 0.0;
+        let result = unsafe {
+            pixGetAverageMasked(
+                self.0,
+                mask,
+                0,
+                0,
+                factor,
+                stat_type.to_leptonica(),
+                &mut val,
+            )
+        };
+        if result != 0 {
+            Err(PixStatsError::Failed)
+        } else {
+            Ok(val)
+        }
+    }
+
+    /// Wrapper for [`pixGetRankValueMasked`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix4.c)
+    ///
+    /// Input: 8 bpp, rank (0.0 for the darkest value, 1.0 for the lightest, 0.5 for the median).
+    pub fn rank_value(&self, rank: l_float32) -> Result<l_float32, PixStatsError> {
+        self.check_depth(&[8], PixStatsError::UnsupportedDepth)?;
+        let mut val = 0.0;
+        let result = unsafe {
+            pixGetRankValueMasked(
+                self.0,
+                std::ptr::null_mut(),
+                0,
+                0,
+                1,
+                rank,
+                &mut val,
+                std::ptr::null_mut(),
+            )
+        };
+        if result != 0 {
+            Err(PixStatsError::Failed)
+        } else {
+            Ok(val)
+        }
+    }
+
+    /// Wrapper for [`pixRowStats`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix4.c)
+    ///
+    /// Input: 8 bpp. Returns the statistic of each row.
+    pub fn row_stats(&self, stat: LineStat) -> Result<RefCountedExclusive<Numa>, PixStatsError> {
+        self.line_stats(stat, pixRowStats)
+    }
+
+    /// Wrapper for [`pixColumnStats`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix4.c)
+    ///
+    /// Input: 8 bpp. Returns the statistic of each column.
+    pub fn column_stats(&self, stat: LineStat) -> Result<RefCountedExclusive<Numa>, PixStatsError> {
+        self.line_stats(stat, pixColumnStats)
+    }
+
+    /// Wrapper for [`pixZero`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pix3.c)
+    ///
+    /// Input: 1 bpp, threshold (largest foreground fraction still considered blank).
+    /// A threshold of 0.0 only accepts images without any foreground pixels.
+    pub fn is_blank(&self, threshold: l_float32) -> Result<bool, PixStatsError> {
+        self.check_depth(&[1], PixStatsError::UnsupportedDepth)?;
+        let mut empty = 0;
+        if unsafe { pixZero(self.0, &mut empty) } != 0 {
+            return Err(PixStatsError::Failed);
+        }
+        if empty != 0 {
+            Ok(true)
+        } else {
+            Ok(self.foreground_fraction()? <= threshold)
+        }
+    }
+
+    fn line_stats(
+        &self,
+        stat: LineStat,
+        stats_fn: LineStatsFn,
+    ) -> Result<RefCountedExclusive<Numa>, PixStatsError> {
+        self.check_depth(&[8], PixStatsError::UnsupportedDepth)?;
+        let mut numa = std::ptr::null_mut();
+        let numa_ptr: *mut *mut leptonica_sys::Numa = &mut numa;
+        let out = |selected| {
+            if stat == selected {
+                numa_ptr
+            } else {
+                std::ptr::null_mut()
+            }
+        };
+        let result = unsafe {
+            stats_fn(
+                self.0,
+                std::ptr::null_mut(),
+                out(LineStat::Mean),
+                out(LineStat::Median),
+                out(LineStat::Mode),
+                out(LineStat::ModeCount),
+                out(LineStat::Variance),
+                out(LineStat::RootVariance),
+            )
+        };
+        if result != 0 || numa.is_null() {
+            unsafe { numaDestroy(&mut numa) };
+            Err(PixStatsError::Failed)
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Numa::new_from_pointer(numa)) })
+        }
+    }
+
+    fn stats_mask(mask: Option<&Pix>) -> Result<*mut leptonica_sys::Pix, PixStatsError> {
+        match mask {
+            None => Ok(std::ptr::null_mut()),
+            Some(mask) => {
+                mask.check_depth(&[1], PixStatsError::UnsupportedDepth)?;
+                Ok(mask.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary_image() -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(4, 2, 1).unwrap();
+        pix.set_pixel(0, 0, 1);
+        pix.set_pixel(3, 1, 1);
+        pix
+    }
+
+    fn gray_image() -> RefCountedExclusive<Pix> {
+        let mut pix = Pix::create(2, 2, 8).unwrap();
+        pix.set_pixel(0, 0, 10);
+        pix.set_pixel(1, 0, 30);
+        pix.set_pixel(0, 1, 50);
+        pix.set_pixel(1, 1, 70);
+        pix
+    }
+
+    #[test]
+    fn count_pixels_test() {
+        let pix = binary_image();
+        assert_eq!(pix.count_pixels(), Ok(2));
+        assert_eq!(pix.foreground_fraction(), Ok(0.25));
+        assert_eq!(
+            gray_image().count_pixels(),
+            Err(PixStatsError::UnsupportedDepth(8))
+        );
+    }
+
+    #[test]
+    fn is_blank_test() {
+        let pix = binary_image();
+        assert_eq!(pix.is_blank(0.0), Ok(false));
+        assert_eq!(pix.is_blank(0.3), Ok(true));
+        assert_eq!(Pix::create(4, 2, 1).unwrap().is_blank(0.0), Ok(true));
+    }
+
+    #[test]
+    fn average_masked_test() {
+        let pix = gray_image();
+        let mean = pix.average_masked(None, 1, StatType::MeanAbsVal).unwrap();
+        assert!((mean - 40.0).abs() < 0.001);
+        let var = pix.average_masked(None, 1, StatType::Variance).unwrap();
+        assert!((var - 500.0).abs() < 0.1);
+
+        let mut mask = Pix::create(2, 2, 1).unwrap();
+        mask.set_pixel(1, 1, 1);
+        let mean = pix
+            .average_masked(Some(&mask), 1, StatType::MeanAbsVal)
+            .unwrap();
+        assert!((mean - 70.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn rank_value_test() {
+        let pix = gray_image();
+        assert!(pix.rank_value(0.0).unwrap() <= 10.0);
+        assert!(pix.rank_value(1.0).unwrap() >= 70.0);
+    }
+
+    #[test]
+    fn line_stats_test() {
+        let pix = gray_image();
+        let means = pix.row_stats(LineStat::Mean).unwrap();
+        assert_eq!(means.to_vec(), vec![20.0, 60.0]);
+        let means = pix.column_stats(LineStat::Mean).unwrap();
+        assert_eq!(means.to_vec(), vec![30.0, 50.0]);
+    }
+}
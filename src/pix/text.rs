@@ -0,0 +1,176 @@
+use super::{Pix, Rgb};
+use crate::{memory::RefCountedExclusive, Bmf};
+
+use leptonica_sys::{
+    l_int32, l_uint32, pixAddTextlines, pixSetTextline, L_ADD_ABOVE, L_ADD_BELOW, L_ADD_LEFT,
+    L_ADD_RIGHT,
+};
+use std::convert::TryInto;
+use std::ffi::CStr;
+use thiserror::Error;
+
+/// Error returned by the Pix text methods
+#[derive(Debug, Error, PartialEq)]
+#[error("Pix text rendering failed")]
+pub struct PixTextError();
+
+/// Where to add text relative to the image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextLocation {
+    /// `L_ADD_ABOVE`
+    Above,
+    /// `L_ADD_BELOW`
+    Below,
+    /// `L_ADD_LEFT`
+    Left,
+    /// `L_ADD_RIGHT`
+    Right,
+}
+
+impl TextLocation {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Above => L_ADD_ABOVE,
+            Self::Below => L_ADD_BELOW,
+            Self::Left => L_ADD_LEFT,
+            Self::Right => L_ADD_RIGHT,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl Pix {
+    /// The pixel value Leptonica expects for text in `color` at this depth
+    fn text_value(&self, color: Rgb) -> l_uint32 {
+        if self.get_colormap().is_some() {
+            // Leptonica picks the nearest colormap entry itself
+            return color.to_pixel();
+        }
+        match self.get_depth() {
+            1 => 1,
+            32 => color.to_pixel(),
+            depth => {
+                let gray = (299 * l_uint32::from(color.r)
+                    + 587 * l_uint32::from(color.g)
+                    + 114 * l_uint32::from(color.b))
+                    / 1000;
+                gray * ((1 << depth) - 1) / 255
+            }
+        }
+    }
+
+    /// Wrapper for [`pixAddTextlines`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/textops.c)
+    ///
+    /// Returns a copy of the image extended at `location` with the lines of `text` rendered in it
+    /// in `color`. On 32 bpp and colormapped images `color` is used as is (or the nearest colormap
+    /// entry), on 2 to 16 bpp gray images its luminance scaled to the depth, and on 1 bpp images
+    /// the text is always foreground.
+    pub fn add_text_lines(
+        &self,
+        bmf: &Bmf,
+        text: &CStr,
+        color: Rgb,
+        location: TextLocation,
+    ) -> Result<RefCountedExclusive<Pix>, PixTextError> {
+        let ptr = unsafe {
+            pixAddTextlines(
+                self.0,
+                *bmf.as_ref(),
+                text.as_ptr(),
+                self.text_value(color),
+                location.to_leptonica(),
+            )
+        };
+        Self::from_result(ptr, PixTextError())
+    }
+
+    /// Wrapper for [`pixSetTextline`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/textops.c)
+    ///
+    /// Renders a single line of `text` in place in `color`, starting at the baseline point (x0, y0).
+    /// `color` is mapped to the depth as in [`Pix::add_text_lines`].
+    /// Returns the width of the rendered text and whether it overflowed the image.
+    pub fn set_textline(
+        &mut self,
+        bmf: &Bmf,
+        text: &CStr,
+        color: Rgb,
+        x0: l_int32,
+        y0: l_int32,
+    ) -> Result<(l_int32, bool), PixTextError> {
+        let (mut width, mut overflow) = (0, 0);
+        let result = unsafe {
+            pixSetTextline(
+                self.0,
+                *bmf.as_ref(),
+                text.as_ptr(),
+                self.text_value(color),
+                x0,
+                y0,
+                &mut width,
+                &mut overflow,
+            )
+        };
+        if result != 0 {
+            Err(PixTextError())
+        } else {
+            Ok((width, overflow != 0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_text_lines_test() {
+        let bmf = Bmf::create(None, 8).unwrap();
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let text = CStr::from_bytes_with_nul(b"box 1\0").unwrap();
+        let below = pix
+            .add_text_lines(&bmf, text, Rgb::new(255, 0, 0), TextLocation::Below)
+            .unwrap();
+        assert_eq!(below.get_width(), 200);
+        assert!(below.get_height() > 23);
+        let right = pix
+            .add_text_lines(&bmf, text, Rgb::new(255, 0, 0), TextLocation::Right)
+            .unwrap();
+        assert!(right.get_width() > 200);
+        assert!(right.get_height() >= 23);
+    }
+
+    #[test]
+    fn set_textline_test() {
+        let bmf = Bmf::create(None, 8).unwrap();
+        let mut pix = Pix::create(100, 20, 32).unwrap();
+        let text = CStr::from_bytes_with_nul(b"0.93\0").unwrap();
+        let (width, overflow) = pix
+            .set_textline(&bmf, text, Rgb::new(0, 255, 0), 2, 15)
+            .unwrap();
+        assert!(width > 0);
+        assert!(!overflow);
+        let green = Rgb::new(0, 255, 0).to_pixel();
+        assert!((0..100).any(|x| (0..20).any(|y| pix.get_pixel(x, y) == Some(green))));
+
+        let (_, overflow) = pix
+            .set_textline(&bmf, text, Rgb::new(0, 255, 0), 90, 15)
+            .unwrap();
+        assert!(overflow);
+    }
+
+    #[test]
+    fn set_textline_depth_test() {
+        let bmf = Bmf::create(None, 8).unwrap();
+        let text = CStr::from_bytes_with_nul(b"0.93\0").unwrap();
+        let mut pix = Pix::create(100, 20, 1).unwrap();
+        pix.set_textline(&bmf, text, Rgb::new(0, 0, 0), 2, 15)
+            .unwrap();
+        assert!((0..100).any(|x| (0..20).any(|y| pix.get_pixel(x, y) == Some(1))));
+
+        let mut pix = Pix::create(100, 20, 8).unwrap();
+        pix.set_textline(&bmf, text, Rgb::new(255, 255, 255), 2, 15)
+            .unwrap();
+        assert!((0..100).any(|x| (0..20).any(|y| pix.get_pixel(x, y) == Some(255))));
+    }
+}
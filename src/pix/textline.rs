@@ -0,0 +1,200 @@
+use super::Pix;
+use crate::{memory::RefCountedExclusive, Boxa, Numa, Pixa, Pta};
+
+use leptonica_sys::{
+    boxaDestroy, l_int32, numaDestroy, pixExtractTextlines, pixFindBaselines,
+    pixGetWordBoxesInTextlines, pixGetWordsInTextlines, pixaDestroy, ptaDestroy,
+};
+use thiserror::Error;
+
+/// Error returned by the Pix text line methods
+#[derive(Debug, Error, PartialEq)]
+pub enum PixTextlineError {
+    #[error("Pix depth {0} is not supported, expected 1")]
+    UnsupportedDepth(l_int32),
+    #[error("Pix text line detection failed")]
+    Failed,
+}
+
+impl Pix {
+    /// Wrapper for [`pixGetWordBoxesInTextlines`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/classapp.c)
+    ///
+    /// Input: 1 bpp, best at 75 to 150 ppi, the smallest and largest word sizes kept.
+    /// Returns the word boxes, sorted by text line, and the text line index of each word.
+    pub fn get_word_boxes_in_textlines(
+        &self,
+        minwidth: l_int32,
+        minheight: l_int32,
+        maxwidth: l_int32,
+        maxheight: l_int32,
+    ) -> Result<(RefCountedExclusive<Boxa>, RefCountedExclusive<Numa>), PixTextlineError> {
+        self.check_depth(&[1], PixTextlineError::UnsupportedDepth)?;
+        let mut boxa = std::ptr::null_mut();
+        let mut nai = std::ptr::null_mut();
+        let result = unsafe {
+            pixGetWordBoxesInTextlines(
+                self.0, minwidth, minheight, maxwidth, maxheight, &mut boxa, &mut nai,
+            )
+        };
+        if result != 0 || boxa.is_null() || nai.is_null() {
+            unsafe {
+                boxaDestroy(&mut boxa);
+                numaDestroy(&mut nai);
+            }
+            return Err(PixTextlineError::Failed);
+        }
+        unsafe {
+            Ok((
+                RefCountedExclusive::new(Boxa::new_from_pointer(boxa)),
+                RefCountedExclusive::new(Numa::new_from_pointer(nai)),
+            ))
+        }
+    }
+
+    /// Wrapper for [`pixGetWordsInTextlines`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/classapp.c)
+    ///
+    /// As [`Pix::get_word_boxes_in_textlines`], also returning the image of each word.
+    #[allow(clippy::type_complexity)]
+    pub fn get_words_in_textlines(
+        &self,
+        minwidth: l_int32,
+        minheight: l_int32,
+        maxwidth: l_int32,
+        maxheight: l_int32,
+    ) -> Result<
+        (
+            RefCountedExclusive<Boxa>,
+            RefCountedExclusive<Pixa>,
+            RefCountedExclusive<Numa>,
+        ),
+        PixTextlineError,
+    > {
+        self.check_depth(&[1], PixTextlineError::UnsupportedDepth)?;
+        let mut boxa = std::ptr::null_mut();
+        let mut pixa = std::ptr::null_mut();
+        let mut nai = std::ptr::null_mut();
+        let result = unsafe {
+            pixGetWordsInTextlines(
+                self.0, minwidth, minheight, maxwidth, maxheight, &mut boxa, &mut pixa, &mut nai,
+            )
+        };
+        if result != 0 || boxa.is_null() || pixa.is_null() || nai.is_null() {
+            unsafe {
+                boxaDestroy(&mut boxa);
+                pixaDestroy(&mut pixa);
+                numaDestroy(&mut nai);
+            }
+            return Err(PixTextlineError::Failed);
+        }
+        unsafe {
+            Ok((
+                RefCountedExclusive::new(Boxa::new_from_pointer(boxa)),
+                RefCountedExclusive::new(Pixa::new_from_pointer(pixa)),
+                RefCountedExclusive::new(Numa::new_from_pointer(nai)),
+            ))
+        }
+    }
+
+    /// Wrapper for [`pixExtractTextlines`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pageseg.c)
+    ///
+    /// Input: any depth, the largest (maxw, maxh) and smallest (minw, minh) line sizes kept and
+    /// the size adjustment (adjw, adjh) of each line; 0 uses the defaults.
+    /// Returns the image of each text line, with its box in the page.
+    pub fn extract_textlines(
+        &self,
+        (maxw, maxh): (l_int32, l_int32),
+        (minw, minh): (l_int32, l_int32),
+        (adjw, adjh): (l_int32, l_int32),
+    ) -> Result<RefCountedExclusive<Pixa>, PixTextlineError> {
+        let ptr = unsafe {
+            pixExtractTextlines(
+                self.0,
+                maxw,
+                maxh,
+                minw,
+                minh,
+                adjw,
+                adjh,
+                std::ptr::null_mut(),
+            )
+        };
+        if ptr.is_null() {
+            Err(PixTextlineError::Failed)
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Pixa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixFindBaselines`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/baseline.c)
+    ///
+    /// Input: 1 bpp, best at 300 ppi. Returns the y value of each baseline and a pair of
+    /// end points for each.
+    pub fn find_baselines(
+        &self,
+    ) -> Result<(RefCountedExclusive<Numa>, RefCountedExclusive<Pta>), PixTextlineError> {
+        self.check_depth(&[1], PixTextlineError::UnsupportedDepth)?;
+        let mut pta = std::ptr::null_mut();
+        let mut na = unsafe { pixFindBaselines(self.0, &mut pta, std::ptr::null_mut()) };
+        if na.is_null() || pta.is_null() {
+            unsafe {
+                numaDestroy(&mut na);
+                ptaDestroy(&mut pta);
+            }
+            return Err(PixTextlineError::Failed);
+        }
+        unsafe {
+            Ok((
+                RefCountedExclusive::new(Numa::new_from_pointer(na)),
+                RefCountedExclusive::new(Pta::new_from_pointer(pta)),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::binary_text_image;
+    use leptonica_sys::pixaGetBoxGeometry;
+
+    #[test]
+    fn word_boxes_test() {
+        let pix = binary_text_image();
+        let (boxa, nai) = pix.get_word_boxes_in_textlines(2, 2, 200, 23).unwrap();
+        assert!(boxa.get_count() > 0);
+        assert_eq!(boxa.get_count(), nai.get_count());
+
+        let (boxa, pixa, nai) = pix.get_words_in_textlines(2, 2, 200, 23).unwrap();
+        assert_eq!(boxa.get_count(), pixa.get_count());
+        assert_eq!(boxa.get_count(), nai.get_count());
+
+        let color = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        assert_eq!(
+            color.get_word_boxes_in_textlines(2, 2, 200, 23).err(),
+            Some(PixTextlineError::UnsupportedDepth(32))
+        );
+    }
+
+    #[test]
+    fn extract_textlines_test() {
+        let pix = Pix::read_mem(include_bytes!("../../image.png")).unwrap();
+        let lines = pix.extract_textlines((50, 50), (20, 8), (0, 0)).unwrap();
+        assert_eq!(lines.get_count(), 1);
+        let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+        let pixa = AsRef::<leptonica_sys::Pixa>::as_ref(&*lines) as *const _ as *mut _;
+        assert_eq!(
+            unsafe { pixaGetBoxGeometry(pixa, 0, &mut x, &mut y, &mut w, &mut h) },
+            0
+        );
+        assert!(x < 10 && x + w > 190 && x + w <= 200);
+        assert!(y < 10 && h >= 8 && y + h <= 23);
+        let line = lines.get_pix_cloned(0).unwrap();
+        assert_eq!((line.get_width(), line.get_height()), (w, h));
+    }
+
+    #[test]
+    fn find_baselines_test() {
+        let (baselines, endpoints) = binary_text_image().find_baselines().unwrap();
+        assert_eq!(endpoints.get_count(), 2 * baselines.get_count());
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
     memory::{LeptonicaDestroy, RefCounted, RefCountedExclusive},
-    Pix,
+    Boxa, Pix,
 };
 use leptonica_sys::{
-    pixaDestroy, pixaGetCount, pixaGetPix, pixaReadMultipageTiff, L_CLONE, L_COPY,
+    l_float32, l_int32, pixaDestroy, pixaDisplayOnLattice, pixaDisplayTiled,
+    pixaDisplayTiledAndScaled, pixaDisplayTiledInColumns, pixaGetCount, pixaGetPix,
+    pixaReadMultipageTiff, L_CLONE, L_COPY,
 };
 use std::{convert::TryInto, ffi::CStr};
 
@@ -11,6 +13,22 @@ use std::{convert::TryInto, ffi::CStr};
 #[derive(Debug, PartialEq)]
 pub struct Pixa(*mut leptonica_sys::Pixa);
 
+/// Color of the background between tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileBackground {
+    White,
+    Black,
+}
+
+impl TileBackground {
+    fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::White => 0,
+            Self::Black => 1,
+        }
+    }
+}
+
 impl AsRef<leptonica_sys::Pixa> for Pixa {
     fn as_ref(&self) -> &leptonica_sys::Pixa {
         unsafe { &*self.0 }
@@ -68,6 +86,92 @@ impl Pixa {
                 .map(|raw| RefCounted::new(Pix::new_from_pointer(raw)))
         }
     }
+
+    /// Wrapper for [`pixaDisplayTiled`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixafunc2.c)
+    ///
+    /// Tiles the images in rows no wider than `max_width`, with `spacing` between them.
+    /// The output depth is the largest depth of the images.
+    pub fn display_tiled(
+        &self,
+        max_width: l_int32,
+        background: TileBackground,
+        spacing: l_int32,
+    ) -> Option<RefCountedExclusive<Pix>> {
+        Self::display_result(unsafe {
+            pixaDisplayTiled(self.0, max_width, background.to_leptonica(), spacing)
+        })
+    }
+
+    /// Wrapper for [`pixaDisplayTiledInColumns`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixafunc2.c)
+    ///
+    /// Tiles the images `nx` to a row, each scaled by `scale_factor`,
+    /// with `spacing` between them and a black `border` around each.
+    pub fn display_tiled_in_columns(
+        &self,
+        nx: l_int32,
+        scale_factor: l_float32,
+        spacing: l_int32,
+        border: l_int32,
+    ) -> Option<RefCountedExclusive<Pix>> {
+        Self::display_result(unsafe {
+            pixaDisplayTiledInColumns(self.0, nx, scale_factor, spacing, border)
+        })
+    }
+
+    /// Wrapper for [`pixaDisplayTiledAndScaled`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixafunc2.c)
+    ///
+    /// Tiles the images `ncols` to a row, each scaled to `tile_width`.
+    /// Input: outdepth (1, 8 or 32)
+    pub fn display_tiled_and_scaled(
+        &self,
+        outdepth: l_int32,
+        tile_width: l_int32,
+        ncols: l_int32,
+        background: TileBackground,
+        spacing: l_int32,
+        border: l_int32,
+    ) -> Option<RefCountedExclusive<Pix>> {
+        Self::display_result(unsafe {
+            pixaDisplayTiledAndScaled(
+                self.0,
+                outdepth,
+                tile_width,
+                ncols,
+                background.to_leptonica(),
+                spacing,
+                border,
+            )
+        })
+    }
+
+    /// Wrapper for [`pixaDisplayOnLattice`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixafunc2.c)
+    ///
+    /// Places each image in a `cell_width` by `cell_height` cell of a square-ish lattice.
+    /// Returns the image, the number of columns and the location of each image.
+    pub fn display_on_lattice(
+        &self,
+        cell_width: l_int32,
+        cell_height: l_int32,
+    ) -> Option<(RefCountedExclusive<Pix>, l_int32, RefCountedExclusive<Boxa>)> {
+        let mut ncols = 0;
+        let mut boxa = std::ptr::null_mut();
+        let pix = Self::display_result(unsafe {
+            pixaDisplayOnLattice(self.0, cell_width, cell_height, &mut ncols, &mut boxa)
+        });
+        let boxa = unsafe {
+            boxa.as_mut()
+                .map(|raw| RefCountedExclusive::new(Boxa::new_from_pointer(raw)))
+        };
+        Some((pix?, ncols, boxa?))
+    }
+
+    fn display_result(ptr: *mut leptonica_sys::Pix) -> Option<RefCountedExclusive<Pix>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
 }
 
 impl LeptonicaDestroy for Pixa {
@@ -93,4 +197,41 @@ mod tests {
         assert!(pixa.get_pix_copied(2).is_none());
         assert!(pixa.get_pix_cloned(2).is_none());
     }
+
+    fn multipage() -> RefCountedExclusive<Pixa> {
+        Pixa::read_multipage_tiff(CStr::from_bytes_with_nul(b"multipage.tiff\0").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn display_tiled_test() {
+        let pix = multipage()
+            .display_tiled(1000, TileBackground::White, 10)
+            .unwrap();
+        assert!(pix.get_width() >= 2 * 165);
+        assert!(pix.get_height() >= 67);
+        assert!(pix.get_height() < 2 * 67);
+    }
+
+    #[test]
+    fn display_tiled_in_columns_test() {
+        let pix = multipage().display_tiled_in_columns(1, 1.0, 0, 0).unwrap();
+        assert_eq!(pix.get_width(), 165);
+        assert_eq!(pix.get_height(), 2 * 67);
+    }
+
+    #[test]
+    fn display_tiled_and_scaled_test() {
+        let pix = multipage()
+            .display_tiled_and_scaled(32, 100, 2, TileBackground::Black, 0, 0)
+            .unwrap();
+        assert_eq!(pix.get_depth(), 32);
+        assert!(pix.get_width() >= 200);
+    }
+
+    #[test]
+    fn display_on_lattice_test() {
+        let (pix, ncols, boxa) = multipage().display_on_lattice(200, 100).unwrap();
+        assert_eq!(pix.get_width(), ncols * 200);
+        assert_eq!(boxa.get_count(), 2);
+    }
 }
@@ -0,0 +1,150 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCounted, RefCountedExclusive},
+    Numa, Pixa,
+};
+
+use leptonica_sys::{
+    l_int32, l_ok, numaDestroy, pixaDestroy, pixaaAddPixa, pixaaCreate, pixaaDestroy,
+    pixaaFlattenToPixa, pixaaGetCount, pixaaGetPixa, L_CLONE, L_COPY,
+};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`Pixaa`](https://tpgit.github.io/Leptonica/struct_pixaa.html) structure
+///
+/// An array of image arrays.
+#[derive(Debug, PartialEq)]
+pub struct Pixaa(*mut leptonica_sys::Pixaa);
+
+impl AsRef<leptonica_sys::Pixaa> for Pixaa {
+    fn as_ref(&self) -> &leptonica_sys::Pixaa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::Pixaa> for Pixaa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::Pixaa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Pixaa {
+    /// Create a new Pixaa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid Pixaa struct.
+    /// The Pixaa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::Pixaa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`pixaaCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixabasic.c)
+    ///
+    /// Input: n (initial number of ptrs)
+    pub fn create(n: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { pixaaCreate(n) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixaaAddPixa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixabasic.c) with copied `copyflag`: `L_COPY`
+    pub fn add_pixa(&mut self, pixa: &Pixa) -> l_ok {
+        let pixa = AsRef::<leptonica_sys::Pixa>::as_ref(pixa) as *const _ as *mut _;
+        unsafe { pixaaAddPixa(self.0, pixa, L_COPY.try_into().unwrap()) }
+    }
+
+    /// Wrapper for [`pixaaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixabasic.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { pixaaGetCount(self.0, std::ptr::null_mut()) }
+    }
+
+    /// Wrapper for [`pixaaGetPixa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixabasic.c) with copied `accesstype`: `L_COPY`
+    pub fn get_pixa_copied(&self, index: l_int32) -> Option<RefCountedExclusive<Pixa>> {
+        unsafe {
+            pixaaGetPixa(self.0, index, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Pixa::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`pixaaGetPixa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixabasic.c) with cloned `accesstype`: `L_CLONE`
+    pub fn get_pixa_cloned(&self, index: l_int32) -> Option<RefCounted<Pixa>> {
+        unsafe {
+            pixaaGetPixa(self.0, index, L_CLONE.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCounted::new(Pixa::new_from_pointer(raw)))
+        }
+    }
+
+    /// Iterates over clones of the inner Pixa, see [`Pixaa::get_pixa_cloned`]
+    pub fn iter_pixa_cloned(&self) -> impl Iterator<Item = RefCounted<Pixa>> + '_ {
+        (0..self.get_count()).filter_map(move |index| self.get_pixa_cloned(index))
+    }
+
+    /// Wrapper for [`pixaaFlattenToPixa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixafunc1.c) with copied `copyflag`: `L_COPY`
+    ///
+    /// Returns all images in a single Pixa, with the index of the inner Pixa each came from.
+    pub fn flatten(&self) -> Option<(RefCountedExclusive<Pixa>, RefCountedExclusive<Numa>)> {
+        let mut naindex = std::ptr::null_mut();
+        let mut pixa =
+            unsafe { pixaaFlattenToPixa(self.0, &mut naindex, L_COPY.try_into().unwrap()) };
+        if pixa.is_null() || naindex.is_null() {
+            unsafe {
+                pixaDestroy(&mut pixa);
+                numaDestroy(&mut naindex);
+            }
+            return None;
+        }
+        unsafe {
+            Some((
+                RefCountedExclusive::new(Pixa::new_from_pointer(pixa)),
+                RefCountedExclusive::new(Numa::new_from_pointer(naindex)),
+            ))
+        }
+    }
+}
+
+impl LeptonicaDestroy for Pixaa {
+    unsafe fn destroy(&mut self) {
+        pixaaDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::multipage;
+
+    #[test]
+    fn get_test() {
+        let pixa = multipage();
+        let mut pixaa = Pixaa::create(2).unwrap();
+        assert_eq!(pixaa.get_count(), 0);
+        pixaa.add_pixa(&pixa);
+        pixaa.add_pixa(&pixa);
+        assert_eq!(pixaa.get_count(), 2);
+
+        assert_eq!(pixaa.get_pixa_copied(0).unwrap().get_count(), 2);
+        assert_eq!(pixaa.get_pixa_cloned(1).unwrap().get_count(), 2);
+        assert!(pixaa.get_pixa_copied(2).is_none());
+        assert!(pixaa.get_pixa_cloned(2).is_none());
+
+        let counts: Vec<_> = pixaa.iter_pixa_cloned().map(|p| p.get_count()).collect();
+        assert_eq!(counts, [2, 2]);
+    }
+
+    #[test]
+    fn flatten_test() {
+        let pixa = multipage();
+        let mut pixaa = Pixaa::create(2).unwrap();
+        pixaa.add_pixa(&pixa);
+        pixaa.add_pixa(&pixa);
+
+        let (flat, naindex) = pixaa.flatten().unwrap();
+        assert_eq!(flat.get_count(), 4);
+        assert_eq!(naindex.to_vec(), [0.0, 0.0, 1.0, 1.0]);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCountedExclusive},
+    CompressionFormat, Pix, PixComp, Pixa,
+};
+
+use leptonica_sys::{
+    free, l_int32, l_ok, pixaCreateFromPixacomp, pixacompCreateFromPixa, pixacompDestroy,
+    pixacompGetCount, pixacompGetPix, pixacompGetPixcomp, pixacompRead, pixacompReadMem,
+    pixacompWrite, pixacompWriteMem, L_CLONE, L_COPY,
+};
+use std::{convert::TryInto, ffi::CStr};
+
+/// Wrapper around Leptonica's [`PixaComp`](https://tpgit.github.io/Leptonica/struct_pixa_comp.html) structure
+///
+/// An array of images held compressed in memory, serialized as `.pac` files.
+#[derive(Debug, PartialEq)]
+pub struct PixaComp(*mut leptonica_sys::PixaComp);
+
+impl AsRef<leptonica_sys::PixaComp> for PixaComp {
+    fn as_ref(&self) -> &leptonica_sys::PixaComp {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::PixaComp> for PixaComp {
+    fn as_mut(&mut self) -> &mut leptonica_sys::PixaComp {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl PixaComp {
+    /// Create a new PixaComp from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid PixaComp struct.
+    /// The PixaComp struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::PixaComp) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`pixacompCreateFromPixa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Compresses every image of the Pixa, see [`PixComp::create_from_pix`].
+    pub fn create_from_pixa(
+        pixa: &Pixa,
+        format: CompressionFormat,
+    ) -> Option<RefCountedExclusive<Self>> {
+        let pixa = AsRef::<leptonica_sys::Pixa>::as_ref(pixa) as *const _ as *mut _;
+        let ptr = unsafe {
+            pixacompCreateFromPixa(pixa, format.to_leptonica(), L_CLONE.try_into().unwrap())
+        };
+        Self::from_result(ptr)
+    }
+
+    /// Wrapper for [`pixacompRead`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Read a `.pac` file.
+    pub fn read(filename: &CStr) -> Option<RefCountedExclusive<Self>> {
+        Self::from_result(unsafe { pixacompRead(filename.as_ptr()) })
+    }
+
+    /// Wrapper for [`pixacompReadMem`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Read the `.pac` serialization from memory.
+    pub fn read_mem(data: &[u8]) -> Option<RefCountedExclusive<Self>> {
+        Self::from_result(unsafe { pixacompReadMem(data.as_ptr(), data.len()) })
+    }
+
+    /// Wrapper for [`pixacompWrite`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Write a `.pac` file.
+    pub fn write(&self, filename: &CStr) -> l_ok {
+        unsafe { pixacompWrite(filename.as_ptr(), self.0) }
+    }
+
+    /// Wrapper for [`pixacompWriteMem`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Returns the `.pac` serialization.
+    pub fn write_mem(&self) -> Option<Vec<u8>> {
+        let mut data = std::ptr::null_mut();
+        let mut size = 0;
+        unsafe {
+            let result = pixacompWriteMem(&mut data, &mut size, self.0);
+            let bytes = if result != 0 || data.is_null() {
+                None
+            } else {
+                Some(std::slice::from_raw_parts(data, size).to_vec())
+            };
+            free(data.cast());
+            bytes
+        }
+    }
+
+    /// Wrapper for [`pixacompGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { pixacompGetCount(self.0) }
+    }
+
+    /// Wrapper for [`pixacompGetPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Decompresses the image at `index`. The index is shifted by the offset stored in the
+    /// PixaComp, which is 0 unless it was read from a file written with an offset.
+    pub fn get_pix(&self, index: l_int32) -> Option<RefCountedExclusive<Pix>> {
+        unsafe {
+            pixacompGetPix(self.0, index)
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Pix::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`pixacompGetPixcomp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c) with copied `copyflag`: `L_COPY`
+    ///
+    /// Returns the still compressed image at `index`.
+    pub fn get_pixcomp_copied(&self, index: l_int32) -> Option<RefCountedExclusive<PixComp>> {
+        unsafe {
+            pixacompGetPixcomp(self.0, index, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(PixComp::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`pixaCreateFromPixacomp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Decompresses every image.
+    pub fn to_pixa(&self) -> Option<RefCountedExclusive<Pixa>> {
+        unsafe {
+            pixaCreateFromPixacomp(self.0, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Pixa::new_from_pointer(raw)))
+        }
+    }
+
+    fn from_result(ptr: *mut leptonica_sys::PixaComp) -> Option<RefCountedExclusive<Self>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for PixaComp {
+    unsafe fn destroy(&mut self) {
+        pixacompDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::multipage;
+
+    #[test]
+    fn create_from_pixa_test() {
+        let pixa = multipage();
+        let pixac = PixaComp::create_from_pixa(&pixa, CompressionFormat::Png).unwrap();
+        assert_eq!(pixac.get_count(), 2);
+        let pix = pixac.get_pix(1).unwrap();
+        assert!(*pix == *pixa.get_pix_cloned(1).unwrap());
+        assert!(pixac.get_pix(2).is_none());
+
+        let pixc = pixac.get_pixcomp_copied(0).unwrap();
+        assert_eq!(
+            pixc.get_dimensions().map(|(w, h, _)| (w, h)),
+            Some((165, 67))
+        );
+        assert_eq!(pixac.to_pixa().unwrap().get_count(), 2);
+    }
+
+    #[test]
+    fn write_mem_test() {
+        let pixac = PixaComp::create_from_pixa(&multipage(), CompressionFormat::Default).unwrap();
+        let data = pixac.write_mem().unwrap();
+        let restored = PixaComp::read_mem(&data).unwrap();
+        assert_eq!(restored.get_count(), 2);
+        assert!(*restored.get_pix(0).unwrap() == *pixac.get_pix(0).unwrap());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCountedExclusive},
+    Pix,
+};
+
+use leptonica_sys::{
+    l_int32, pixCreateFromPixcomp, pixcompCreateFromPix, pixcompDestroy, pixcompGetDimensions,
+    IFF_DEFAULT, IFF_JFIF_JPEG, IFF_PNG, IFF_TIFF_G4,
+};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`PixComp`](https://tpgit.github.io/Leptonica/struct_pix_comp.html) structure
+///
+/// An image held compressed in memory.
+#[derive(Debug, PartialEq)]
+pub struct PixComp(*mut leptonica_sys::PixComp);
+
+/// Format used to compress an image held in memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionFormat {
+    /// `IFF_DEFAULT`: tiff g4 for 1 bpp, jpeg for 8 and 32 bpp without colormap, png otherwise
+    Default,
+    /// `IFF_TIFF_G4`: lossless, 1 bpp only
+    TiffG4,
+    /// `IFF_PNG`: lossless
+    Png,
+    /// `IFF_JFIF_JPEG`: lossy, 8 and 32 bpp without colormap only
+    Jpeg,
+}
+
+impl CompressionFormat {
+    pub(crate) fn to_leptonica(self) -> l_int32 {
+        match self {
+            Self::Default => IFF_DEFAULT,
+            Self::TiffG4 => IFF_TIFF_G4,
+            Self::Png => IFF_PNG,
+            Self::Jpeg => IFF_JFIF_JPEG,
+        }
+        .try_into()
+        .unwrap()
+    }
+}
+
+impl AsRef<leptonica_sys::PixComp> for PixComp {
+    fn as_ref(&self) -> &leptonica_sys::PixComp {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::PixComp> for PixComp {
+    fn as_mut(&mut self) -> &mut leptonica_sys::PixComp {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl PixComp {
+    /// Create a new PixComp from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid PixComp struct.
+    /// The PixComp struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::PixComp) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`pixcompCreateFromPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Input: any depth, the compression format. Falls back to png if the format does not
+    /// support the depth.
+    pub fn create_from_pix(
+        pix: &Pix,
+        format: CompressionFormat,
+    ) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { pixcompCreateFromPix(*pix.as_ref(), format.to_leptonica()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixCreateFromPixcomp`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Decompresses the image.
+    pub fn create_pix(&self) -> Option<RefCountedExclusive<Pix>> {
+        let ptr = unsafe { pixCreateFromPixcomp(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`pixcompGetDimensions`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/pixcomp.c)
+    ///
+    /// Returns the width, height and depth of the image without decompressing it.
+    pub fn get_dimensions(&self) -> Option<(l_int32, l_int32, l_int32)> {
+        let (mut w, mut h, mut d) = (0, 0, 0);
+        if unsafe { pixcompGetDimensions(self.0, &mut w, &mut h, &mut d) } != 0 {
+            None
+        } else {
+            Some((w, h, d))
+        }
+    }
+}
+
+impl LeptonicaDestroy for PixComp {
+    unsafe fn destroy(&mut self) {
+        pixcompDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+        let pixc = PixComp::create_from_pix(&pix, CompressionFormat::Png).unwrap();
+        assert_eq!(
+            pixc.get_dimensions(),
+            Some((pix.get_width(), pix.get_height(), pix.get_depth()))
+        );
+        let restored = pixc.create_pix().unwrap();
+        assert!(*restored == *pix);
+    }
+}
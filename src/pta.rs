@@ -0,0 +1,110 @@
+use crate::memory::{LeptonicaClone, LeptonicaDestroy, RefCountedExclusive};
+
+use leptonica_sys::{
+    l_float32, l_int32, l_ok, ptaAddPt, ptaClone, ptaCreate, ptaDestroy, ptaGetCount, ptaGetIPt,
+    ptaGetPt,
+};
+
+/// Wrapper around Leptonica's [`Pta`](https://tpgit.github.io/Leptonica/struct_pta.html) structure
+///
+/// An array of points.
+#[derive(Debug, PartialEq)]
+pub struct Pta(*mut leptonica_sys::Pta);
+
+impl AsRef<*mut leptonica_sys::Pta> for Pta {
+    fn as_ref(&self) -> &*mut leptonica_sys::Pta {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::Pta> for Pta {
+    fn as_ref(&self) -> &leptonica_sys::Pta {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::Pta> for Pta {
+    fn as_mut(&mut self) -> &mut leptonica_sys::Pta {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Pta {
+    /// Create a new Pta from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid Pta struct.
+    /// The Pta struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::Pta) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`ptaCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c)
+    ///
+    /// Input: n (initial array size)
+    pub fn create(n: l_int32) -> Option<RefCountedExclusive<Self>> {
+        let ptr = unsafe { ptaCreate(n) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`ptaAddPt`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c)
+    pub fn add_pt(&mut self, x: l_float32, y: l_float32) -> l_ok {
+        unsafe { ptaAddPt(self.0, x, y) }
+    }
+
+    /// Wrapper for [`ptaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { ptaGetCount(self.0) }
+    }
+
+    /// Wrapper for [`ptaGetPt`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c)
+    pub fn get_pt(&self, index: l_int32) -> Option<(l_float32, l_float32)> {
+        let (mut x, mut y) = (0.0, 0.0);
+        if unsafe { ptaGetPt(self.0, index, &mut x, &mut y) } != 0 {
+            None
+        } else {
+            Some((x, y))
+        }
+    }
+
+    /// Wrapper for [`ptaGetIPt`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c)
+    ///
+    /// The point rounded to integer coordinates.
+    pub fn get_ipt(&self, index: l_int32) -> Option<(l_int32, l_int32)> {
+        let (mut x, mut y) = (0, 0);
+        if unsafe { ptaGetIPt(self.0, index, &mut x, &mut y) } != 0 {
+            None
+        } else {
+            Some((x, y))
+        }
+    }
+}
+
+impl LeptonicaDestroy for Pta {
+    unsafe fn destroy(&mut self) {
+        ptaDestroy(&mut self.0);
+    }
+}
+
+impl LeptonicaClone for Pta {
+    unsafe fn clone(&mut self) -> Self {
+        Self::new_from_pointer(ptaClone(self.0))
+    }
+}
+
+#[test]
+fn add_get_test() {
+    let mut pta = Pta::create(2).unwrap();
+    assert_eq!(pta.get_count(), 0);
+    pta.add_pt(1.5, 2.0);
+    pta.add_pt(3.0, 4.25);
+    assert_eq!(pta.get_count(), 2);
+    assert_eq!(pta.get_pt(0), Some((1.5, 2.0)));
+    assert_eq!(pta.get_ipt(1), Some((3, 4)));
+    assert_eq!(pta.get_pt(2), None);
+}
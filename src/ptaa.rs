@@ -0,0 +1,72 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCounted, RefCountedExclusive},
+    Pta,
+};
+
+use leptonica_sys::{l_int32, ptaaDestroy, ptaaGetCount, ptaaGetPta, L_CLONE, L_COPY};
+use std::convert::TryInto;
+
+/// Wrapper around Leptonica's [`Ptaa`](https://tpgit.github.io/Leptonica/struct_ptaa.html) structure
+///
+/// An array of point arrays.
+#[derive(Debug, PartialEq)]
+pub struct Ptaa(*mut leptonica_sys::Ptaa);
+
+impl AsRef<*mut leptonica_sys::Ptaa> for Ptaa {
+    fn as_ref(&self) -> &*mut leptonica_sys::Ptaa {
+        &self.0
+    }
+}
+
+impl AsRef<leptonica_sys::Ptaa> for Ptaa {
+    fn as_ref(&self) -> &leptonica_sys::Ptaa {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::Ptaa> for Ptaa {
+    fn as_mut(&mut self) -> &mut leptonica_sys::Ptaa {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Ptaa {
+    /// Create a new Ptaa from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid Ptaa struct.
+    /// The Ptaa struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::Ptaa) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`ptaaGetCount`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c)
+    pub fn get_count(&self) -> l_int32 {
+        unsafe { ptaaGetCount(self.0) }
+    }
+
+    /// Wrapper for [`ptaaGetPta`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c) with copied `accessflag`: `L_COPY`
+    pub fn get_pta_copied(&self, index: l_int32) -> Option<RefCountedExclusive<Pta>> {
+        unsafe {
+            ptaaGetPta(self.0, index, L_COPY.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCountedExclusive::new(Pta::new_from_pointer(raw)))
+        }
+    }
+
+    /// Wrapper for [`ptaaGetPta`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/ptabasic.c) with cloned `accessflag`: `L_CLONE`
+    pub fn get_pta_cloned(&self, index: l_int32) -> Option<RefCounted<Pta>> {
+        unsafe {
+            ptaaGetPta(self.0, index, L_CLONE.try_into().unwrap())
+                .as_mut()
+                .map(|raw| RefCounted::new(Pta::new_from_pointer(raw)))
+        }
+    }
+}
+
+impl LeptonicaDestroy for Ptaa {
+    unsafe fn destroy(&mut self) {
+        ptaaDestroy(&mut self.0);
+    }
+}
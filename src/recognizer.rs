@@ -0,0 +1,293 @@
+use crate::{
+    memory::{LeptonicaDestroy, RefCountedExclusive},
+    Pix, Pixa, Str,
+};
+
+use leptonica_sys::{
+    free, l_float32, l_int32, rchExtract, recogCreate, recogCreateFromPixa, recogDestroy,
+    recogIdentifyPix, recogReadMem, recogTrainLabeled, recogTrainingFinished, recogWriteMem,
+};
+use std::ffi::CStr;
+use thiserror::Error;
+
+/// Wrapper around Leptonica's [`L_Recog`](https://tpgit.github.io/Leptonica/struct_l___recog.html) structure
+///
+/// A character recognizer that matches 1 bpp images against labeled templates.
+#[derive(Debug, PartialEq)]
+pub struct Recognizer(*mut leptonica_sys::L_Recog);
+
+/// Error returned by Recognizer::create, Recognizer::create_from_pixa and Recognizer::read_mem
+#[derive(Debug, Error)]
+#[error("Recognizer::create returned null")]
+pub struct RecognizerCreateError();
+
+/// Error returned by the Recognizer training and identification methods
+#[derive(Debug, Error, PartialEq)]
+pub enum RecognizerError {
+    #[error("Pix depth {0} is not supported, expected 1")]
+    UnsupportedDepth(l_int32),
+    #[error("Failed to train the labeled image")]
+    Train,
+    #[error("Failed to finish the training")]
+    TrainingFinished,
+    #[error("Failed to identify the image")]
+    Identify,
+    #[error("Failed to serialize the recognizer")]
+    Write,
+}
+
+/// Result of [`Recognizer::identify`]
+#[derive(Debug)]
+pub struct Identification {
+    /// Index of the best matching class
+    pub index: l_int32,
+    /// Label of the best matching class
+    pub label: Str,
+    /// Correlation score of the best match, from 0.0 to 1.0
+    pub score: l_float32,
+}
+
+impl AsRef<leptonica_sys::L_Recog> for Recognizer {
+    fn as_ref(&self) -> &leptonica_sys::L_Recog {
+        unsafe { &*self.0 }
+    }
+}
+
+impl AsMut<leptonica_sys::L_Recog> for Recognizer {
+    fn as_mut(&mut self) -> &mut leptonica_sys::L_Recog {
+        unsafe { &mut *self.0 }
+    }
+}
+
+impl Recognizer {
+    /// Create a new Recognizer from a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be to a valid L_Recog struct.
+    /// The L_Recog struct must not be mutated whilst the wrapper exists.
+    pub unsafe fn new_from_pointer(p: *mut leptonica_sys::L_Recog) -> Self {
+        Self(p)
+    }
+
+    /// Wrapper for [`recogCreate`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogbasic.c)
+    ///
+    /// Input: scalew and scaleh (size templates are scaled to; 0 for no scaling), linew (width
+    /// strokes are normalized to; 0 to skip), threshold (for binarizing 32 bpp training images;
+    /// 0 for default) and maxyshift (vertical search for a match; 0 or 1).
+    /// Train with [`Recognizer::train_labeled`] then [`Recognizer::finish_training`].
+    pub fn create(
+        scalew: l_int32,
+        scaleh: l_int32,
+        linew: l_int32,
+        threshold: l_int32,
+        maxyshift: l_int32,
+    ) -> Result<RefCountedExclusive<Self>, RecognizerCreateError> {
+        Self::from_create(unsafe { recogCreate(scalew, scaleh, linew, threshold, maxyshift) })
+    }
+
+    /// Wrapper for [`recogCreateFromPixa`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogbasic.c)
+    ///
+    /// Input: images labeled by their text field, and the parameters of
+    /// [`Recognizer::create`]. The returned recognizer is already trained.
+    pub fn create_from_pixa(
+        pixa: &Pixa,
+        scalew: l_int32,
+        scaleh: l_int32,
+        linew: l_int32,
+        threshold: l_int32,
+        maxyshift: l_int32,
+    ) -> Result<RefCountedExclusive<Self>, RecognizerCreateError> {
+        let pixa = AsRef::<leptonica_sys::Pixa>::as_ref(pixa) as *const _ as *mut _;
+        Self::from_create(unsafe {
+            recogCreateFromPixa(pixa, scalew, scaleh, linew, threshold, maxyshift)
+        })
+    }
+
+    /// Wrapper for [`recogReadMem`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogbasic.c)
+    ///
+    /// Read a trained recognizer serialized with [`Recognizer::write_mem`].
+    pub fn read_mem(data: &[u8]) -> Result<RefCountedExclusive<Self>, RecognizerCreateError> {
+        Self::from_create(unsafe { recogReadMem(data.as_ptr(), data.len()) })
+    }
+
+    /// Wrapper for [`recogWriteMem`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogbasic.c)
+    pub fn write_mem(&self) -> Result<Vec<u8>, RecognizerError> {
+        let mut data = std::ptr::null_mut();
+        let mut size = 0;
+        unsafe {
+            let result = recogWriteMem(&mut data, &mut size, self.0);
+            let bytes = if result != 0 || data.is_null() {
+                Err(RecognizerError::Write)
+            } else {
+                Ok(std::slice::from_raw_parts(data, size).to_vec())
+            };
+            free(data.cast());
+            bytes
+        }
+    }
+
+    /// Wrapper for [`recogTrainLabeled`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogtrain.c)
+    ///
+    /// Input: 1 or 32 bpp image of a single character and its label.
+    pub fn train_labeled(&mut self, pix: &Pix, label: &CStr) -> Result<(), RecognizerError> {
+        let result = unsafe {
+            recogTrainLabeled(
+                self.0,
+                *pix.as_ref(),
+                std::ptr::null_mut(),
+                label.as_ptr() as *mut _,
+                0,
+            )
+        };
+        if result != 0 {
+            Err(RecognizerError::Train)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`recogTrainingFinished`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogtrain.c)
+    ///
+    /// Prepares the templates for identification, using the default minimum template size
+    /// and fraction of usable templates. Leptonica destroys a recognizer whose training
+    /// is not usable, so it is consumed.
+    pub fn finish_training(
+        mut recog: RefCountedExclusive<Self>,
+    ) -> Result<RefCountedExclusive<Self>, RecognizerError> {
+        let result = unsafe { recogTrainingFinished(&mut recog.0, 1, -1, -1.0) };
+        if result != 0 || recog.0.is_null() {
+            Err(RecognizerError::TrainingFinished)
+        } else {
+            Ok(recog)
+        }
+    }
+
+    /// Wrapper for [`recogIdentifyPix`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogident.c)
+    /// and [`rchExtract`](https://github.com/DanBloomberg/leptonica/blob/1.82.0/src/recogident.c)
+    ///
+    /// Input: 1 bpp image of a single character. Returns the best matching class.
+    pub fn identify(&mut self, pix: &Pix) -> Result<Identification, RecognizerError> {
+        pix.check_depth(&[1], RecognizerError::UnsupportedDepth)?;
+        if unsafe { recogIdentifyPix(self.0, *pix.as_ref(), std::ptr::null_mut()) } != 0 {
+            return Err(RecognizerError::Identify);
+        }
+        let rch = AsRef::<leptonica_sys::L_Recog>::as_ref(self).rch;
+        let (mut index, mut score, mut text) = (0, 0.0, std::ptr::null_mut());
+        let result = unsafe {
+            rchExtract(
+                rch,
+                &mut index,
+                &mut score,
+                &mut text,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        if result != 0 || text.is_null() {
+            unsafe { free(text.cast()) };
+            return Err(RecognizerError::Identify);
+        }
+        Ok(Identification {
+            index,
+            label: unsafe { Str::new_from_pointer(text) },
+            score,
+        })
+    }
+
+    /// Identifies each image of the Pixa in turn, see [`Recognizer::identify`]
+    pub fn identify_pixa(&mut self, pixa: &Pixa) -> Result<Vec<Identification>, RecognizerError> {
+        (0..pixa.get_count())
+            .map(|index| {
+                let pix = pixa
+                    .get_pix_cloned(index)
+                    .ok_or(RecognizerError::Identify)?;
+                self.identify(&pix)
+            })
+            .collect()
+    }
+
+    fn from_create(
+        ptr: *mut leptonica_sys::L_Recog,
+    ) -> Result<RefCountedExclusive<Self>, RecognizerCreateError> {
+        if ptr.is_null() {
+            Err(RecognizerCreateError())
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Self(ptr)) })
+        }
+    }
+}
+
+impl LeptonicaDestroy for Recognizer {
+    unsafe fn destroy(&mut self) {
+        recogDestroy(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bmf;
+    use leptonica_sys::{bmfGetPix, pixSetText, pixaAddPix, pixaCreate, L_COPY};
+    use std::{convert::TryInto, ffi::CString};
+
+    /// The digits 0 to 9 rendered in the built-in font of each size, with their labels
+    fn digits(fontsizes: &[l_int32]) -> Vec<(RefCountedExclusive<Pix>, CString)> {
+        let mut glyphs = Vec::new();
+        for &fontsize in fontsizes {
+            let bmf = Bmf::create(None, fontsize).unwrap();
+            for digit in b'0'..=b'9' {
+                let pix = unsafe { bmfGetPix(*bmf.as_ref(), digit as _) };
+                assert!(!pix.is_null());
+                glyphs.push((
+                    unsafe { RefCountedExclusive::new(Pix::new_from_pointer(pix)) },
+                    CString::new(vec![digit]).unwrap(),
+                ));
+            }
+        }
+        glyphs
+    }
+
+    fn labeled_pixa(fontsizes: &[l_int32]) -> RefCountedExclusive<Pixa> {
+        let mut pixa = unsafe { RefCountedExclusive::new(Pixa::new_from_pointer(pixaCreate(0))) };
+        for (mut pix, label) in digits(fontsizes) {
+            unsafe {
+                pixSetText(pix.as_mut(), label.as_ptr());
+                pixaAddPix(pixa.as_mut(), pix.as_mut(), L_COPY.try_into().unwrap());
+            }
+        }
+        pixa
+    }
+
+    #[test]
+    fn train_test() {
+        let mut recog = Recognizer::create(0, 40, 0, 0, 1).unwrap();
+        for (pix, label) in digits(&[16, 18, 20]) {
+            recog.train_labeled(&pix, &label).unwrap();
+        }
+        let mut recog = Recognizer::finish_training(recog).unwrap();
+
+        let (pix, _) = &digits(&[20])[7];
+        let identification = recog.identify(pix).unwrap();
+        assert_eq!(identification.label.to_str(), Ok("7"));
+        assert!(identification.score > 0.9);
+
+        let data = recog.write_mem().unwrap();
+        let mut restored = Recognizer::read_mem(&data).unwrap();
+        assert_eq!(restored.identify(pix).unwrap().label.to_str(), Ok("7"));
+    }
+
+    #[test]
+    fn create_from_pixa_test() {
+        let pixa = labeled_pixa(&[16, 18, 20]);
+        let mut recog = Recognizer::create_from_pixa(&pixa, 0, 40, 0, 0, 1).unwrap();
+        let identifications = recog.identify_pixa(&labeled_pixa(&[20])).unwrap();
+        let labels: Vec<_> = identifications
+            .iter()
+            .map(|i| i.label.to_str().unwrap())
+            .collect();
+        assert_eq!(labels, ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]);
+    }
+}
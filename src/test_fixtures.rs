@@ -0,0 +1,53 @@
+//! Images shared by the tests of several modules
+
+use crate::{memory::RefCountedExclusive, Bmf, Pix, Pixa};
+
+use leptonica_sys::{pixConvertTo1, pixSetTextblock};
+use std::ffi::{CStr, CString};
+
+/// The text of image.png, binarized at 128
+pub(crate) fn binary_text_image() -> RefCountedExclusive<Pix> {
+    let pix = Pix::read_mem(include_bytes!("../image.png")).unwrap();
+    unsafe { RefCountedExclusive::new(Pix::new_from_pointer(pixConvertTo1(*pix.as_ref(), 128))) }
+}
+
+/// 1000 by 1200 page with a block of about 30 lines of text, enough to build page models
+pub(crate) fn text_page() -> RefCountedExclusive<Pix> {
+    let bmf = Bmf::create(None, 10).unwrap();
+    let mut pix = Pix::create(1000, 1200, 1).unwrap();
+    let text =
+        CString::new(["leptonica plumbing reads and dewarps pages of text"; 40].join(" ")).unwrap();
+    let mut overflow = 0;
+    let result = unsafe {
+        pixSetTextblock(
+            pix.as_mut(),
+            *bmf.as_ref(),
+            text.as_ptr(),
+            1,
+            50,
+            50,
+            900,
+            0,
+            &mut overflow,
+        )
+    };
+    assert_eq!(result, 0);
+    pix
+}
+
+/// 7 by 7 image with a 5 by 5 square outline
+pub(crate) fn ring_image() -> RefCountedExclusive<Pix> {
+    let mut pix = Pix::create(7, 7, 1).unwrap();
+    for i in 1..6 {
+        pix.set_pixel(i, 1, 1);
+        pix.set_pixel(i, 5, 1);
+        pix.set_pixel(1, i, 1);
+        pix.set_pixel(5, i, 1);
+    }
+    pix
+}
+
+/// The two pages of multipage.tiff
+pub(crate) fn multipage() -> RefCountedExclusive<Pixa> {
+    Pixa::read_multipage_tiff(CStr::from_bytes_with_nul(b"multipage.tiff\0").unwrap()).unwrap()
+}
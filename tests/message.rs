@@ -0,0 +1,58 @@
+use leptonica_plumbing::message::{capture, route_to_log};
+use leptonica_plumbing::{Pix, PixReadMemError};
+
+fn read_missing_file() {
+    unsafe { leptonica_sys::pixRead(b"fail\0".as_ptr().cast()) };
+}
+
+#[test]
+fn capture_test() {
+    route_to_log();
+    let ((), messages) = capture(read_missing_file);
+    assert!(messages.iter().any(|m| m.starts_with("Error in")));
+
+    let ((), messages) = capture(|| ());
+    assert!(messages.is_empty());
+}
+
+#[test]
+fn nested_capture_test() {
+    route_to_log();
+    let (((), inner), outer) = capture(|| capture(read_missing_file));
+    assert!(!inner.is_empty());
+    assert!(outer.is_empty());
+}
+
+#[test]
+fn capture_panic_test() {
+    route_to_log();
+    let ((), outer) = capture(|| {
+        let panicked = std::panic::catch_unwind(|| capture(|| panic!("inner")));
+        assert!(panicked.is_err());
+        read_missing_file();
+    });
+    assert!(outer.iter().any(|m| m.starts_with("Error in")));
+
+    // Messages outside of any capture are only logged
+    read_missing_file();
+    let ((), messages) = capture(|| ());
+    assert!(messages.is_empty());
+}
+
+#[test]
+fn read_error_messages_test() {
+    route_to_log();
+    let path = std::ffi::CString::new("fail").unwrap();
+    let error = Pix::read(&path).err().unwrap();
+    assert!(error.messages.iter().any(|m| m.contains("pixRead")));
+    assert!(error
+        .to_string()
+        .starts_with("Pix::read returned null: Error in"));
+
+    match Pix::read_mem(&[]).err() {
+        Some(PixReadMemError::NullPtr { messages }) => {
+            assert!(messages.iter().any(|m| m.contains("pixReadMem")))
+        }
+        error => panic!("unexpected {:?}", error),
+    }
+}